@@ -0,0 +1,86 @@
+// Membaca satuan_bawaan.json saat kompilasi agar faktor satuan tetap di besaran.rs berasal dari
+// tabel yang sama dengan registry runtime. Hasilnya $OUT_DIR/satuan_tetap.rs, berisi TABEL_TETAP.
+use serde_json::Value;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const TABEL: &str = "src/satuan_bawaan.json";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", TABEL);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let teks = fs::read_to_string(TABEL).unwrap_or_else(|e| panic!("gagal membaca {}: {}", TABEL, e));
+    let tabel: Vec<Value> = serde_json::from_str(&teks).unwrap_or_else(|e| panic!("{} tidak valid: {}", TABEL, e));
+
+    let mut keluaran = String::from("// Dibuat oleh build.rs dari src/satuan_bawaan.json; jangan disunting\n");
+    keluaran.push_str("pub const TABEL_TETAP: &[FaktorTetap] = &[\n");
+    for satuan in &tabel {
+        let simbol = satuan["simbol"].as_str().unwrap_or_else(|| panic!("satuan tanpa simbol: {}", satuan));
+        let faktor = match &satuan["faktor"] {
+            Value::String(teks) => teks.clone(),
+            Value::Number(angka) => angka.to_string(),
+            lain => panic!("faktor '{}' tidak valid: {}", simbol, lain),
+        };
+        let (pembilang, penyebut) =
+            pecahan(&faktor).unwrap_or_else(|| panic!("faktor '{}' ({}) tidak dapat dibaca persis", simbol, faktor));
+        let offset = satuan["offset"].as_f64().unwrap_or(0.0);
+        writeln!(
+            keluaran,
+            "    FaktorTetap {{ simbol: {:?}, pembilang: {:?}, penyebut: {:?}, offset: {:?} }},",
+            simbol, pembilang as f64, penyebut as f64, offset
+        )
+        .unwrap();
+    }
+    keluaran.push_str("];\n");
+
+    let tujuan = Path::new(&env::var("OUT_DIR").unwrap()).join("satuan_tetap.rs");
+    fs::write(&tujuan, keluaran).unwrap_or_else(|e| panic!("gagal menulis {}: {}", tujuan.display(), e));
+}
+
+// "0.0254" -> (127, 5000), "5/9" -> (5, 9); sama dengan Faktor::dari_teks, yang menyederhanakan pecahannya
+fn pecahan(teks: &str) -> Option<(u128, u128)> {
+    let (pembilang, penyebut) = match teks.split_once('/') {
+        Some((atas, bawah)) => {
+            let (a, b) = desimal(atas.trim())?;
+            let (c, d) = desimal(bawah.trim())?;
+            // (a/b) / (c/d) = (a·d) / (b·c); disederhanakan silang dulu agar tidak melimpah
+            let (a, c) = sederhanakan(a, c)?;
+            let (d, b) = sederhanakan(d, b)?;
+            (a.checked_mul(d)?, b.checked_mul(c)?)
+        }
+        None => desimal(teks.trim())?,
+    };
+    sederhanakan(pembilang, penyebut)
+}
+
+// Desimal positif tertulis menjadi pembilang/penyebut: "1.5e-3" -> (15, 10000)
+fn desimal(teks: &str) -> Option<(u128, u128)> {
+    let (mantisa, eksponen) = match teks.split_once(['e', 'E']) {
+        Some((mantisa, eksponen)) => (mantisa, eksponen.parse::<i32>().ok()?),
+        None => (teks, 0),
+    };
+    let (bulat, pecahan) = mantisa.split_once('.').unwrap_or((mantisa, ""));
+    let digit: u128 = format!("{}{}", bulat, pecahan).parse().ok()?;
+    let skala = eksponen - pecahan.len() as i32;
+    let pangkat = 10u128.checked_pow(skala.unsigned_abs())?;
+    if skala >= 0 {
+        Some((digit.checked_mul(pangkat)?, 1))
+    } else {
+        Some((digit, pangkat))
+    }
+}
+
+fn sederhanakan(pembilang: u128, penyebut: u128) -> Option<(u128, u128)> {
+    if penyebut == 0 {
+        return None;
+    }
+    let (mut a, mut b) = (pembilang, penyebut);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    let fpb = a.max(1);
+    Some((pembilang / fpb, penyebut / fpb))
+}
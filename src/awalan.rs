@@ -0,0 +1,117 @@
+use crate::pesan::{self, Bahasa};
+use serde::{Deserialize, Serialize};
+
+// Jenis awalan yang boleh dipasang pada sebuah satuan dasar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JenisAwalan {
+    #[default]
+    Tidak,
+    Si,
+    Biner,
+    // Data: kelipatan SI mulai kilo (kB, TB, ...) dan awalan biner; tanpa pecahan seperti "mB"
+    Data,
+    Semua,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Awalan {
+    pub simbol: &'static [&'static str],
+    pub nama: &'static [&'static str],
+    pub faktor: f64,
+    pub biner: bool,
+}
+
+// Simbol pertama dipakai untuk tampilan. Nama pertama adalah ejaan Indonesia dan nama
+// terakhir ejaan Inggris; nama tampilan dipilih menurut bahasa antarmuka
+pub const AWALAN: &[Awalan] = &[
+    Awalan { simbol: &["Q"], nama: &["quetta"], faktor: 1e30, biner: false },
+    Awalan { simbol: &["R"], nama: &["ronna"], faktor: 1e27, biner: false },
+    Awalan { simbol: &["Y"], nama: &["yota", "yotta"], faktor: 1e24, biner: false },
+    Awalan { simbol: &["Z"], nama: &["zeta", "zetta"], faktor: 1e21, biner: false },
+    Awalan { simbol: &["E"], nama: &["eksa", "exa"], faktor: 1e18, biner: false },
+    Awalan { simbol: &["P"], nama: &["peta"], faktor: 1e15, biner: false },
+    Awalan { simbol: &["T"], nama: &["tera"], faktor: 1e12, biner: false },
+    Awalan { simbol: &["G"], nama: &["giga"], faktor: 1e9, biner: false },
+    Awalan { simbol: &["M"], nama: &["mega"], faktor: 1e6, biner: false },
+    Awalan { simbol: &["k"], nama: &["kilo"], faktor: 1e3, biner: false },
+    Awalan { simbol: &["h"], nama: &["hekto", "hecto"], faktor: 1e2, biner: false },
+    Awalan { simbol: &["da"], nama: &["deka", "deca"], faktor: 1e1, biner: false },
+    Awalan { simbol: &["d"], nama: &["desi", "deci"], faktor: 1e-1, biner: false },
+    Awalan { simbol: &["c"], nama: &["senti", "centi"], faktor: 1e-2, biner: false },
+    Awalan { simbol: &["m"], nama: &["mili", "milli"], faktor: 1e-3, biner: false },
+    Awalan { simbol: &["µ", "μ", "u"], nama: &["mikro", "micro"], faktor: 1e-6, biner: false },
+    Awalan { simbol: &["n"], nama: &["nano"], faktor: 1e-9, biner: false },
+    Awalan { simbol: &["p"], nama: &["piko", "pico"], faktor: 1e-12, biner: false },
+    Awalan { simbol: &["f"], nama: &["femto"], faktor: 1e-15, biner: false },
+    Awalan { simbol: &["a"], nama: &["atto"], faktor: 1e-18, biner: false },
+    Awalan { simbol: &["z"], nama: &["zepto"], faktor: 1e-21, biner: false },
+    Awalan { simbol: &["y"], nama: &["yokto", "yocto"], faktor: 1e-24, biner: false },
+    Awalan { simbol: &["r"], nama: &["ronto"], faktor: 1e-27, biner: false },
+    Awalan { simbol: &["q"], nama: &["quecto"], faktor: 1e-30, biner: false },
+    Awalan { simbol: &["Ki"], nama: &["kibi"], faktor: 1024.0, biner: true },
+    Awalan { simbol: &["Mi"], nama: &["mebi"], faktor: 1048576.0, biner: true },
+    Awalan { simbol: &["Gi"], nama: &["gibi"], faktor: 1073741824.0, biner: true },
+    Awalan { simbol: &["Ti"], nama: &["tebi"], faktor: 1099511627776.0, biner: true },
+    Awalan { simbol: &["Pi"], nama: &["pebi"], faktor: 1125899906842624.0, biner: true },
+];
+
+// Bagaimana awalan ditemukan pada input: lewat simbol ("km") atau nama ("kilometer")
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BentukAwalan {
+    Simbol,
+    Nama,
+}
+
+impl JenisAwalan {
+    pub fn menerima(&self, awalan: &Awalan) -> bool {
+        match self {
+            JenisAwalan::Tidak => false,
+            JenisAwalan::Si => !awalan.biner,
+            JenisAwalan::Biner => awalan.biner,
+            JenisAwalan::Data => awalan.biner || awalan.faktor >= 1e3,
+            JenisAwalan::Semua => true,
+        }
+    }
+}
+
+impl Awalan {
+    pub fn simbol_utama(&self) -> &'static str {
+        self.simbol[0]
+    }
+
+    pub fn nama_tampilan(&self) -> &'static str {
+        match pesan::bahasa() {
+            Bahasa::Id => self.nama[0],
+            Bahasa::En => self.nama[self.nama.len() - 1],
+        }
+    }
+}
+
+// Semua cara memecah input menjadi (awalan, sisa). Simbol dicocokkan persis
+// (huruf besar/kecil berarti: "M" mega, "m" mili), nama tanpa memperhatikan huruf besar.
+pub fn pisahkan(input: &str) -> Vec<(&'static Awalan, BentukAwalan, &str)> {
+    let mut hasil = Vec::new();
+
+    for awalan in AWALAN {
+        for simbol in awalan.simbol {
+            if let Some(sisa) = input.strip_prefix(simbol) {
+                if !sisa.is_empty() {
+                    hasil.push((awalan, BentukAwalan::Simbol, sisa));
+                }
+            }
+        }
+        for nama in awalan.nama {
+            let cocok = input
+                .get(..nama.len())
+                .is_some_and(|depan| depan.eq_ignore_ascii_case(nama));
+            if cocok && input.len() > nama.len() {
+                hasil.push((awalan, BentukAwalan::Nama, &input[nama.len()..]));
+            }
+        }
+    }
+
+    // Awalan terpanjang dicoba lebih dulu ("da" sebelum "d")
+    hasil.sort_by_key(|(_, _, sisa)| sisa.len());
+    hasil
+}
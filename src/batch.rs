@@ -0,0 +1,128 @@
+use crate::converter;
+use crate::history;
+use crate::kalkulator;
+use crate::models::{CatatanKonversi, UnitRegistry};
+use crate::pembulatan::FormatAngka;
+use crate::pesan;
+use crate::saran;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+pub struct RingkasanBatch {
+    pub berhasil: usize,
+    pub gagal: usize,
+}
+
+// Membaca baris "<nilai> <asal> <tujuan>" (atau "<nilai> <asal>" bila `tujuan` diberikan) dari
+// berkas atau stdin. Setiap hasil ditulis satu baris "<nilai> <simbol>"; baris yang gagal dilaporkan
+// ke stderr beserta nomornya dan menghasilkan baris kosong, begitu pula baris kosong dan komentar
+// ("#"), agar baris keluaran ke-N selalu milik baris masukan ke-N.
+// Riwayat ditulis sekali di akhir, bukan per baris.
+pub fn jalankan(
+    sumber: Option<&Path>,
+    tujuan: Option<&str>,
+    simpan_riwayat: bool,
+    angka: FormatAngka,
+) -> Result<RingkasanBatch, String> {
+    let pembaca: Box<dyn BufRead> = match sumber {
+        Some(path) if path != Path::new("-") => {
+            let file = File::open(path).map_err(|e| pesan::format("umum.gagal_buka_berkas", &[&path.display(), &e]))?;
+            Box::new(BufReader::new(file))
+        }
+        _ => Box::new(BufReader::new(io::stdin().lock())),
+    };
+
+    let mut keluaran = BufWriter::new(io::stdout().lock());
+    let mut catatan = Vec::new();
+    let mut ringkasan = RingkasanBatch { berhasil: 0, gagal: 0 };
+    // Catatan tafsiran ejaan ambigu dicetak sekali per batch, bukan di setiap baris
+    let mut sudah_dicatat = HashSet::new();
+    let mut tafsiran = Vec::new();
+
+    // Dibaca per bait agar satu baris yang bukan UTF-8 hanya menggagalkan baris itu sendiri
+    for (i, bait) in pembaca.split(b'\n').enumerate() {
+        let nomor = i + 1;
+        let bait = bait.map_err(|e| pesan::format("batch.gagal_baca_baris", &[&nomor, &e]))?;
+        let baris = String::from_utf8(bait);
+        let baris = baris.as_deref().map(str::trim);
+
+        let hasil = if baris.is_ok_and(|baris| baris.is_empty() || baris.starts_with('#')) {
+            // Baris kosong dan komentar tidak dikonversi, tetapi tetap mendapat baris kosong
+            writeln!(keluaran)
+        } else {
+            let hasil = match baris {
+                Ok(baris) => proses_baris(baris, tujuan, angka, &mut tafsiran),
+                Err(_) => Err((pesan::teks("batch.bukan_utf8").to_string(), None)),
+            };
+            if tafsiran.iter().any(|teks| !sudah_dicatat.contains(teks)) {
+                let _ = keluaran.flush();
+                for teks in tafsiran.drain(..).filter(|teks| sudah_dicatat.insert(teks.clone())) {
+                    eprintln!("{}", pesan::format("umum.catatan", &[&teks]));
+                }
+            }
+            tafsiran.clear();
+            match hasil {
+                Ok((teks, catatan_baris)) => {
+                    ringkasan.berhasil += 1;
+                    catatan.push(catatan_baris);
+                    writeln!(keluaran, "{}", teks)
+                }
+                Err((pesan, catatan_baris)) => {
+                    ringkasan.gagal += 1;
+                    catatan.extend(catatan_baris);
+                    // Kosongkan buffer dulu agar urutan stdout dan stderr tetap wajar di terminal
+                    let _ = keluaran.flush();
+                    eprintln!("{}", pesan::galat(pesan::format("batch.baris", &[&nomor, &pesan])));
+                    writeln!(keluaran)
+                }
+            }
+        };
+        // Pipa ditutup (misal "| head"): berhenti tanpa dianggap gagal
+        if hasil.is_err() {
+            break;
+        }
+    }
+    let _ = keluaran.flush();
+
+    if simpan_riwayat {
+        history::simpan_banyak_riwayat(catatan);
+    }
+    Ok(ringkasan)
+}
+
+fn proses_baris(
+    baris: &str,
+    tujuan: Option<&str>,
+    angka: FormatAngka,
+    tafsiran: &mut Vec<String>,
+) -> Result<(String, CatatanKonversi), (String, Option<CatatanKonversi>)> {
+    let (nilai, dari, ke) = match tujuan {
+        Some(ke) => kalkulator::pisahkan_teks_nilai(baris).map(|(nilai, dari)| (nilai, dari, ke)),
+        None => kalkulator::pisahkan_kueri(baris),
+    }
+    .ok_or_else(|| {
+        let format = pesan::teks(if tujuan.is_some() { "batch.format_dengan_tujuan" } else { "batch.format_lengkap" });
+        (pesan::format("batch.format_tidak_valid", &[&baris, &format]), None)
+    })?;
+    let angka = angka.untuk_teks(nilai);
+    let nilai = angka.lokal.urai_nilai(nilai).map_err(|galat| (galat.to_string(), None))?;
+
+    let catatan = |nilai_output, pesan_error| CatatanKonversi {
+        satuan_asal: dari.to_string(),
+        satuan_tujuan: ke.to_string(),
+        nilai_input: nilai,
+        nilai_output,
+        pesan_error,
+    };
+
+    tafsiran.extend(saran::catatan_konversi(UnitRegistry::global(), dari, ke));
+    match converter::konversi_teks(nilai, dari, ke) {
+        Ok((_, satuan_tujuan, hasil)) => {
+            let teks = format!("{} {}", angka.format(hasil), satuan_tujuan.simbol());
+            Ok((teks, catatan(Some(hasil), None)))
+        }
+        Err(galat) => Err((galat.to_string(), Some(catatan(None, Some(galat.to_string()))))),
+    }
+}
@@ -0,0 +1,453 @@
+//! Lapisan bertipe di atas converter: kategori dan satuan diperiksa saat kompilasi.
+//!
+//! ```
+//! use minirustcli::besaran::{Km, Length, Mile, M};
+//!
+//! let jarak: Length<Km> = Length::new(5.0);
+//! let dalam_mil: Length<Mile> = jarak.ke();
+//! let total = jarak + Length::<M>::new(250.0);
+//! assert_eq!(total.nilai(), 5.25);
+//! assert!((dalam_mil.nilai() - 3.106855961).abs() < 1e-9);
+//! ```
+//!
+//! Besaran beda kategori tidak dapat dijumlahkan:
+//!
+//! ```compile_fail,E0271
+//! use minirustcli::besaran::{Kg, Km, Length, Mass};
+//!
+//! let _ = Length::<Km>::new(5.0) + Mass::<Kg>::new(1.0);
+//! ```
+//!
+//! dan alias kategori hanya menerima satuan dari kategorinya:
+//!
+//! ```compile_fail,E0271
+//! use minirustcli::besaran::{Kg, Length};
+//!
+//! let _berat: Length<Kg> = Length::new(1.0);
+//! ```
+//!
+//! Faktor adalah konstanta sehingga konversi hanya berupa perkalian; runtime `Satuan` tetap dipakai CLI.
+//! Faktornya dibaca dari satuan_bawaan.json saat kompilasi (build.rs), jadi hanya simbolnya yang ditulis
+//! di sini; simbol yang tidak ada di tabel membuat kompilasi gagal.
+use crate::awalan::AWALAN;
+use crate::converter::KonversiError;
+use crate::models::{Kategori, Kuantitas, Satuan};
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Sub};
+
+// Penanda kategori pada tingkat tipe
+pub trait KategoriTetap {
+    const KATEGORI: Kategori;
+}
+
+// Satuan pada tingkat tipe. Nilai dasar = (nilai + OFFSET) * PEMBILANG / PENYEBUT,
+// sama dengan definisi di tabel satuan bawaan.
+pub trait SatuanTetap {
+    type Kategori: KategoriTetap;
+    const SIMBOL: &'static str;
+    const PEMBILANG: f64;
+    const PENYEBUT: f64;
+    const OFFSET: f64;
+}
+
+// Satu baris tabel satuan bawaan dengan faktor yang sudah disederhanakan
+#[derive(Debug, Clone, Copy)]
+pub struct FaktorTetap {
+    pub simbol: &'static str,
+    pub pembilang: f64,
+    pub penyebut: f64,
+    pub offset: f64,
+}
+
+include!(concat!(env!("OUT_DIR"), "/satuan_tetap.rs"));
+
+// Faktor satuan menurut simbolnya, langsung dari tabel atau dengan awalan ("kPa" = k + Pa)
+// seperti UnitRegistry::cari. Dievaluasi saat kompilasi, sehingga simbol yang salah ditolak kompiler.
+const fn faktor_tetap(simbol: &str) -> FaktorTetap {
+    let simbol = simbol.as_bytes();
+    if let Some(faktor) = cari_tabel(simbol) {
+        return faktor;
+    }
+
+    let mut a = 0;
+    while a < AWALAN.len() {
+        let awalan = AWALAN[a].simbol[0].as_bytes();
+        if awalan.len() < simbol.len() && sama(awalan, simbol.split_at(awalan.len()).0) {
+            if let Some(dasar) = cari_tabel(simbol.split_at(awalan.len()).1) {
+                let faktor = AWALAN[a].faktor;
+                // Awalan pecahan (m, µ) masuk ke penyebut agar tetap bilangan bulat seperti Faktor
+                let (pembilang, penyebut) = if faktor >= 1.0 {
+                    (dasar.pembilang * faktor, dasar.penyebut)
+                } else {
+                    (dasar.pembilang, dasar.penyebut * (1.0 / faktor))
+                };
+                return FaktorTetap { simbol: dasar.simbol, pembilang, penyebut, offset: dasar.offset / faktor };
+            }
+        }
+        a += 1;
+    }
+    panic!("simbol satuan tetap tidak ada di satuan_bawaan.json")
+}
+
+const fn cari_tabel(simbol: &[u8]) -> Option<FaktorTetap> {
+    let mut i = 0;
+    while i < TABEL_TETAP.len() {
+        if sama(TABEL_TETAP[i].simbol.as_bytes(), simbol) {
+            return Some(TABEL_TETAP[i]);
+        }
+        i += 1;
+    }
+    None
+}
+
+const fn sama(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// Parameter K selalu sama dengan kategori U; dengan menyebutnya di alias (Length<U> =
+// Besaran<U, Panjang>) satuan dari kategori lain, misal Length<Kg>, ditolak kompiler
+pub struct Besaran<U: SatuanTetap<Kategori = K>, K: KategoriTetap = <U as SatuanTetap>::Kategori> {
+    nilai: f64,
+    satuan: PhantomData<(U, K)>,
+}
+
+pub type Temperature<U> = Besaran<U, Suhu>;
+pub type Length<U> = Besaran<U, Panjang>;
+pub type Area<U> = Besaran<U, Luas>;
+pub type Mass<U> = Besaran<U, Berat>;
+pub type Volume<U> = Besaran<U, Isi>;
+pub type Time<U> = Besaran<U, Waktu>;
+pub type Speed<U> = Besaran<U, Kecepatan>;
+pub type Pressure<U> = Besaran<U, Tekanan>;
+pub type Energy<U> = Besaran<U, Energi>;
+pub type Power<U> = Besaran<U, Daya>;
+pub type Data<U> = Besaran<U, Informasi>;
+
+// Ringkasan satu satuan tetap, untuk membandingkan faktornya dengan tabel runtime
+#[derive(Debug, Clone, Copy)]
+pub struct InfoSatuanTetap {
+    pub simbol: &'static str,
+    pub kategori: Kategori,
+    pub pembilang: f64,
+    pub penyebut: f64,
+    pub offset: f64,
+}
+
+impl InfoSatuanTetap {
+    const fn dari<U: SatuanTetap>() -> InfoSatuanTetap {
+        InfoSatuanTetap {
+            simbol: U::SIMBOL,
+            kategori: <U::Kategori as KategoriTetap>::KATEGORI,
+            pembilang: U::PEMBILANG,
+            penyebut: U::PENYEBUT,
+            offset: U::OFFSET,
+        }
+    }
+}
+
+macro_rules! kategori_tetap {
+    ($($nama:ident => $kategori:ident),* $(,)?) => {
+        $(
+            pub struct $nama;
+            impl KategoriTetap for $nama {
+                const KATEGORI: Kategori = Kategori::$kategori;
+            }
+        )*
+    };
+}
+
+kategori_tetap! {
+    Suhu => Suhu,
+    Panjang => Panjang,
+    Luas => Luas,
+    Berat => Berat,
+    Isi => Volume,
+    Waktu => Waktu,
+    Kecepatan => Kecepatan,
+    Tekanan => Tekanan,
+    Energi => Energi,
+    Daya => Daya,
+    Informasi => Data,
+}
+
+macro_rules! satuan_tetap {
+    ($($nama:ident: $kategori:ident, $simbol:literal;)*) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct $nama;
+            impl SatuanTetap for $nama {
+                type Kategori = $kategori;
+                const SIMBOL: &'static str = $simbol;
+                const PEMBILANG: f64 = faktor_tetap($simbol).pembilang;
+                const PENYEBUT: f64 =
+                    faktor_tetap($simbol).penyebut * <$kategori as KategoriTetap>::KATEGORI.penyebut_dasar();
+                const OFFSET: f64 = faktor_tetap($simbol).offset;
+            }
+        )*
+
+        pub const SEMUA_SATUAN: &[InfoSatuanTetap] = &[$(InfoSatuanTetap::dari::<$nama>()),*];
+    };
+}
+
+satuan_tetap! {
+    Celsius: Suhu, "°C";
+    Fahrenheit: Suhu, "°F";
+    Kelvin: Suhu, "K";
+
+    M: Panjang, "m";
+    Cm: Panjang, "cm";
+    Mm: Panjang, "mm";
+    Km: Panjang, "km";
+    Inch: Panjang, "inch";
+    Mile: Panjang, "miles";
+    Um: Panjang, "µm";
+    Ft: Panjang, "ft";
+    Yd: Panjang, "yd";
+    Nmi: Panjang, "nmi";
+
+    M2: Luas, "m²";
+    Cm2: Luas, "cm²";
+    Km2: Luas, "km²";
+    Ha: Luas, "ha";
+    Are: Luas, "are";
+    Acre: Luas, "acre";
+    Ft2: Luas, "ft²";
+    In2: Luas, "in²";
+    Mi2: Luas, "mi²";
+    Tumbak: Luas, "tumbak";
+    Bau: Luas, "bau";
+
+    Kg: Berat, "kg";
+    G: Berat, "g";
+    Lb: Berat, "lbs";
+    Oz: Berat, "oz";
+    OzT: Berat, "oz t";
+    Mg: Berat, "mg";
+    Tonne: Berat, "t";
+    Stone: Berat, "st";
+    Ons: Berat, "ons";
+    Kuintal: Berat, "kuintal";
+
+    // Volume dalam m³ seperti Satuan::ke_dasar
+    L: Isi, "L";
+    Ml: Isi, "ml";
+    Gal: Isi, "US gal";
+    GalImp: Isi, "imp gal";
+    M3: Isi, "m³";
+    FlOz: Isi, "US fl oz";
+    FlOzImp: Isi, "imp fl oz";
+    Cup: Isi, "cup";
+    Tbsp: Isi, "tbsp";
+    Tsp: Isi, "tsp";
+    Pint: Isi, "US pt";
+    PintImp: Isi, "imp pt";
+    Quart: Isi, "US qt";
+    QuartImp: Isi, "imp qt";
+    Barrel: Isi, "bbl";
+
+    S: Waktu, "s";
+    Min: Waktu, "min";
+    H: Waktu, "h";
+    Ms: Waktu, "ms";
+    Day: Waktu, "d";
+    Week: Waktu, "wk";
+    Year: Waktu, "yr";
+
+    Mps: Kecepatan, "m/s";
+    Kmh: Kecepatan, "km/h";
+    Mph: Kecepatan, "mph";
+
+    Pa: Tekanan, "Pa";
+    Kpa: Tekanan, "kPa";
+    Mpa: Tekanan, "MPa";
+    Bar: Tekanan, "bar";
+    Mbar: Tekanan, "mbar";
+    Atm: Tekanan, "atm";
+    Psi: Tekanan, "psi";
+    MmHg: Tekanan, "mmHg";
+    InHg: Tekanan, "inHg";
+    Torr: Tekanan, "Torr";
+    KgfCm2: Tekanan, "kgf/cm²";
+
+    J: Energi, "J";
+    Kj: Energi, "kJ";
+    Mj: Energi, "MJ";
+    Cal: Energi, "cal";
+    Kcal: Energi, "kcal";
+    Wh: Energi, "Wh";
+    Kwh: Energi, "kWh";
+    Btu: Energi, "BTU";
+    Ev: Energi, "eV";
+    Therm: Energi, "therm";
+
+    W: Daya, "W";
+    Kw: Daya, "kW";
+    Mw: Daya, "MW";
+    Hp: Daya, "hp";
+    Ps: Daya, "PS";
+    BtuPerH: Daya, "BTU/h";
+    Tr: Daya, "TR";
+
+    B: Informasi, "B";
+    Kb: Informasi, "KB";
+    Mb: Informasi, "MB";
+    Gb: Informasi, "GB";
+    Kib: Informasi, "KiB";
+    Mib: Informasi, "MiB";
+    Gib: Informasi, "GiB";
+}
+
+impl<U: SatuanTetap> Besaran<U> {
+    pub const fn new(nilai: f64) -> Besaran<U> {
+        Besaran { nilai, satuan: PhantomData }
+    }
+
+    pub fn nilai(&self) -> f64 {
+        self.nilai
+    }
+
+    fn dasar(&self) -> f64 {
+        (self.nilai + U::OFFSET) * U::PEMBILANG / U::PENYEBUT
+    }
+
+    // Konversi ke satuan lain sekategori; kategori berbeda ditolak kompiler.
+    // Pembilang dan penyebut dikalikan dulu agar pecahan seperti gal -> L tidak menambah galat.
+    pub fn ke<V: SatuanTetap<Kategori = U::Kategori>>(self) -> Besaran<V> {
+        let faktor = (U::PEMBILANG * V::PENYEBUT) / (U::PENYEBUT * V::PEMBILANG);
+        Besaran::new((self.nilai + U::OFFSET) * faktor - V::OFFSET)
+    }
+
+    // Jembatan ke API runtime (misal untuk dicetak lewat keluaran CLI)
+    pub fn ke_kuantitas(self) -> Option<Kuantitas> {
+        Satuan::dari_str(U::SIMBOL).map(|satuan| Kuantitas::new(self.nilai, satuan))
+    }
+}
+
+// Kuantitas runtime ke tipe tetap; gagal jika kategorinya berbeda
+impl<U: SatuanTetap> TryFrom<Kuantitas> for Besaran<U> {
+    type Error = KonversiError;
+
+    fn try_from(kuantitas: Kuantitas) -> Result<Besaran<U>, KonversiError> {
+        let satuan = Satuan::dari_str(U::SIMBOL).ok_or_else(|| KonversiError::SatuanTidakDikenali {
+            peran: "tujuan",
+            input: U::SIMBOL.to_string(),
+            saran: None,
+        })?;
+        if satuan.kategori() != Some(<U::Kategori as KategoriTetap>::KATEGORI) {
+            return Err(KonversiError::DimensiBerbeda {
+                dari: kuantitas.satuan.nama().to_string(),
+                dimensi_dari: kuantitas.satuan.dimensi(),
+                ke: satuan.nama().to_string(),
+                dimensi_ke: satuan.dimensi(),
+            });
+        }
+        Ok(Besaran::new(kuantitas.to(&satuan)?.nilai))
+    }
+}
+
+// Derive tidak dipakai agar U tidak perlu Clone/Debug
+impl<U: SatuanTetap> Clone for Besaran<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U: SatuanTetap> Copy for Besaran<U> {}
+
+impl<U: SatuanTetap> fmt::Debug for Besaran<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Besaran({} {})", self.nilai, U::SIMBOL)
+    }
+}
+
+impl<U: SatuanTetap> fmt::Display for Besaran<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(presisi) => write!(f, "{:.*} {}", presisi, self.nilai, U::SIMBOL),
+            None => write!(f, "{} {}", self.nilai, U::SIMBOL),
+        }
+    }
+}
+
+// Penjumlahan antar satuan sekategori, hasil dalam satuan operan kiri
+impl<U: SatuanTetap, V: SatuanTetap<Kategori = U::Kategori>> Add<Besaran<V>> for Besaran<U> {
+    type Output = Besaran<U>;
+
+    fn add(self, lain: Besaran<V>) -> Besaran<U> {
+        Besaran::new(self.nilai + lain.ke::<U>().nilai)
+    }
+}
+
+impl<U: SatuanTetap, V: SatuanTetap<Kategori = U::Kategori>> Sub<Besaran<V>> for Besaran<U> {
+    type Output = Besaran<U>;
+
+    fn sub(self, lain: Besaran<V>) -> Besaran<U> {
+        Besaran::new(self.nilai - lain.ke::<U>().nilai)
+    }
+}
+
+impl<U: SatuanTetap> Mul<f64> for Besaran<U> {
+    type Output = Besaran<U>;
+
+    fn mul(self, skalar: f64) -> Besaran<U> {
+        Besaran::new(self.nilai * skalar)
+    }
+}
+
+// Panjang × panjang = luas (dalam m²)
+impl<U, V> Mul<Besaran<V>> for Besaran<U>
+where
+    U: SatuanTetap<Kategori = Panjang>,
+    V: SatuanTetap<Kategori = Panjang>,
+{
+    type Output = Area<M2>;
+
+    fn mul(self, lain: Besaran<V>) -> Area<M2> {
+        Besaran::new(self.dasar() * lain.dasar())
+    }
+}
+
+impl<U: SatuanTetap> Div<f64> for Besaran<U> {
+    type Output = Besaran<U>;
+
+    fn div(self, skalar: f64) -> Besaran<U> {
+        Besaran::new(self.nilai / skalar)
+    }
+}
+
+// Jarak / waktu = kecepatan (dalam m/s)
+impl<U, V> Div<Besaran<V>> for Besaran<U>
+where
+    U: SatuanTetap<Kategori = Panjang>,
+    V: SatuanTetap<Kategori = Waktu>,
+{
+    type Output = Speed<Mps>;
+
+    fn div(self, waktu: Besaran<V>) -> Speed<Mps> {
+        Besaran::new(self.dasar() / waktu.dasar())
+    }
+}
+
+impl<U: SatuanTetap, V: SatuanTetap<Kategori = U::Kategori>> PartialEq<Besaran<V>> for Besaran<U> {
+    fn eq(&self, lain: &Besaran<V>) -> bool {
+        self.dasar() == lain.dasar()
+    }
+}
+
+impl<U: SatuanTetap, V: SatuanTetap<Kategori = U::Kategori>> PartialOrd<Besaran<V>> for Besaran<U> {
+    fn partial_cmp(&self, lain: &Besaran<V>) -> Option<Ordering> {
+        self.dasar().partial_cmp(&lain.dasar())
+    }
+}
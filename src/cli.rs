@@ -1,24 +1,295 @@
-use clap::{Parser, Subcommand};
+use clap::{Arg, ArgAction, ArgGroup, Command, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use crate::keluaran::FormatKeluaran;
+use crate::lokal::Lokal;
+use crate::models::Sistem;
+use crate::pesan::{self, Bahasa};
+use crate::pembulatan::{AngkaPenting, FormatAngka, ModePembulatan, Presisi};
+use clap_complete::Shell;
+use std::ffi::OsString;
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "unitconv")]
 #[command(version = "0.1.0")]
 #[command(about = "Aplikasi konversi satuan suhu, panjang, dan berat", long_about = None)]
+#[command(arg_required_else_help = true)]
+#[command(after_help = "Kode keluar: 0 berhasil, 1 kesalahan umum, 2 argumen salah, 3 nilai bukan angka, \
+4 NaN/Infinity, 5 satuan tidak dikenali, 6 dimensi berbeda, 7 di bawah nol mutlak, 8 overflow")]
 pub struct Cli {
+    /// Berkas definisi satuan tambahan (TOML atau JSON), faktor terhadap satuan dasar
+    /// kategori (°C, m, m², kg, L, s, m/s, Pa, J, W, B). Bawaan: ~/.config/unitconv/units.toml
+    #[arg(long, global = true, value_name = "BERKAS")]
+    pub units_file: Option<PathBuf>,
+
+    /// Format keluaran untuk convert, list dan history (skema JSON/TSV: modul keluaran)
+    #[arg(long, global = true, value_enum, default_value_t = FormatKeluaran::Text)]
+    pub output: FormatKeluaran,
+
+    /// Hitung konversi dengan pecahan persis, misal 1 inch = 127/5000 m
+    #[arg(long, global = true, conflicts_with = "big_decimal")]
+    pub exact: bool,
+
+    /// Dengan --exact: tampilkan desimal dengan N digit alih-alih pecahan
+    #[arg(long, global = true, value_name = "N", requires = "exact")]
+    pub digits: Option<usize>,
+
+    /// Jumlah digit di belakang koma pada hasil
+    #[arg(long, global = true, value_name = "N", conflicts_with = "sig_figs")]
+    pub precision: Option<usize>,
+
+    /// Jumlah angka penting pada hasil, atau "auto" untuk mengikuti nilai masukan
+    #[arg(long, global = true, value_name = "N|auto")]
+    pub sig_figs: Option<AngkaPenting>,
+
+    /// Cara pembulatan untuk --precision dan --sig-figs
+    #[arg(long, global = true, value_enum, default_value_t = ModePembulatan::HalfEven)]
+    pub rounding: ModePembulatan,
+
+    /// Lokal angka untuk --value dan hasil: id-ID (1.234,5), en-US (1,234.5), de-DE, fr-FR, sv-SE, en-GB,
+    /// atau "auto" dari LC_ALL/LC_NUMERIC/LANG. Tanpa opsi ini format lama tetap dipakai
+    #[arg(long, global = true, env = "UNITCONV_LOCALE", value_name = "LOKAL")]
+    pub locale: Option<Lokal>,
+
+    /// Bahasa pesan dan bantuan; bawaan dari LC_ALL/LC_MESSAGES/LANG
+    #[arg(long, global = true, value_enum, value_name = "BAHASA")]
+    pub lang: Option<Bahasa>,
+
+    /// Tekanan atmosfer acuan untuk satuan ukur (psig, barg, kPag), misal "14.7 psi" atau "1013 hPa";
+    /// angka tanpa satuan berarti Pa. Bawaan: 1 atm
+    #[arg(long, global = true, env = "UNITCONV_ATMOSPHERE", value_name = "TEKANAN")]
+    pub atmosphere: Option<String>,
+
+    /// Sistem satuan adat untuk nama ambigu (gal, fl oz, pint, quart). Bawaan: us
+    #[arg(long, global = true, value_enum, env = "UNITCONV_SYSTEM", value_name = "SISTEM")]
+    pub system: Option<Sistem>,
+
+    /// Hitung dengan desimal presisi sembarang (butuh fitur cargo "big-decimal")
+    #[arg(long, global = true)]
+    pub big_decimal: bool,
+
     #[command(subcommand)]
-    pub command: Commands,
+    pub command: Option<Commands>,
+
+    /// Konversi singkat tanpa subperintah, misal: 10kg lbs, "5.5 miles in km", 98.6F to C
+    #[arg(allow_hyphen_values = true, value_name = "KUERI")]
+    pub kueri: Vec<String>,
+}
+
+impl Cli {
+    pub fn format_angka(&self) -> FormatAngka {
+        let presisi = match (self.precision, self.sig_figs) {
+            (Some(n), _) => Presisi::Desimal(n),
+            (None, Some(AngkaPenting::Tetap(n))) => Presisi::AngkaPenting(n),
+            (None, Some(AngkaPenting::Otomatis)) => Presisi::Otomatis,
+            (None, None) => Presisi::Penuh,
+        };
+        FormatAngka { presisi, mode: self.rounding, lokal: self.locale.unwrap_or_default() }
+    }
+
+    // Kueri singkat menerima nilai berawalan '-' (-40°F), sehingga opsi yang ditulis sesudahnya
+    // ("10kg lbs --output json") ikut terbaca sebagai kueri. Opsi itu dipindah ke depan lalu diurai ulang.
+    pub fn urai() -> Cli {
+        let argumen: Vec<OsString> = std::env::args_os().collect();
+        let _ = pesan::pasang_bahasa(bahasa_argumen(&argumen).unwrap_or_else(Bahasa::dari_env));
+        let cli = Cli::urai_dari(argumen.clone());
+        let posisi_opsi = cli.kueri.iter().position(|k| k.starts_with("--"));
+        let (None, Some(posisi_opsi)) = (&cli.command, posisi_opsi) else {
+            return cli;
+        };
+
+        let awal_kueri = argumen.len() - cli.kueri.len();
+        let mut baru: Vec<OsString> = argumen[..awal_kueri].to_vec();
+        // "--" yang sudah ditulis pengguna diganti dengan pemisah di bawah
+        if baru.last().is_some_and(|a| a == "--") {
+            baru.pop();
+        }
+        baru.extend(cli.kueri[posisi_opsi..].iter().map(OsString::from));
+        baru.push("--".into());
+        baru.extend(cli.kueri[..posisi_opsi].iter().map(OsString::from));
+        Cli::urai_dari(baru)
+    }
+
+    fn urai_dari(argumen: Vec<OsString>) -> Cli {
+        let cocok = Cli::perintah().get_matches_from(argumen);
+        Cli::from_arg_matches(&cocok).unwrap_or_else(|galat| galat.exit())
+    }
+}
+
+impl Cli {
+    // Definisi clap dengan teks bantuan dalam bahasa aktif, juga dipakai untuk skrip pelengkapan
+    pub fn perintah() -> Command {
+        terjemahkan(Cli::command(), "bantuan")
+    }
+}
+
+// --lang dibaca lebih dulu karena teks bantuan clap sudah harus diterjemahkan saat argumen diurai
+fn bahasa_argumen(argumen: &[OsString]) -> Option<Bahasa> {
+    let mut sisa = argumen.iter().skip(1).map(|a| a.to_string_lossy().into_owned());
+    while let Some(arg) = sisa.next() {
+        if arg == "--" {
+            break;
+        }
+        let nilai = match arg.strip_prefix("--lang") {
+            Some("") => sisa.next()?,
+            Some(nilai) if nilai.starts_with('=') => nilai[1..].to_string(),
+            _ => continue,
+        };
+        return Bahasa::from_str(&nilai, true).ok();
+    }
+    None
+}
+
+const URUTAN_GLOBAL: usize = 1000;
+const URUTAN_BANTUAN: usize = 2000;
+
+// Teks bantuan dari katalog pesan: bantuan.<argumen> untuk perintah utama,
+// bantuan.<subperintah>.<argumen> untuk subperintah, ".tentang" untuk deskripsi perintah
+fn terjemahkan(mut perintah: Command, jalur: &str) -> Command {
+    if let Some(teks) = pesan::coba(&format!("{}.tentang", jalur)) {
+        perintah = perintah.about(teks);
+    }
+
+    // Urutan tampil dipasang ulang karena judul kustom mengacak urutan; opsi global di belakang
+    let id_argumen: Vec<String> = perintah.get_arguments().map(|a| a.get_id().to_string()).collect();
+    for (urutan, id) in id_argumen.into_iter().enumerate() {
+        let bantuan = pesan::coba(&format!("{}.{}", jalur, id));
+        perintah = perintah.mut_arg(id, |arg| {
+            let judul = if arg.is_positional() { "bantuan.judul_argumen" } else { "bantuan.judul_opsi" };
+            let urutan = if arg.is_global_set() { URUTAN_GLOBAL + urutan } else { urutan };
+            let arg = arg.help_heading(pesan::teks(judul)).display_order(urutan);
+            match bantuan {
+                Some(teks) => arg.help(teks),
+                None => arg,
+            }
+        });
+    }
+
+    let subperintah: Vec<String> = perintah.get_subcommands().map(|s| s.get_name().to_string()).collect();
+    for nama in subperintah {
+        let jalur_sub = format!("{}.{}", jalur, nama);
+        perintah = perintah.mut_subcommand(nama, |sub| terjemahkan(sub, &jalur_sub));
+    }
+
+    // -h dan -V bawaan clap diganti agar teks bantuannya ikut diterjemahkan
+    let opsi = pesan::teks("bantuan.judul_opsi");
+    perintah = perintah.disable_help_flag(true).arg(
+        Arg::new("help")
+            .short('h')
+            .long("help")
+            .action(ArgAction::Help)
+            .help(pesan::teks("bantuan.help"))
+            .help_heading(opsi)
+            .display_order(URUTAN_BANTUAN),
+    );
+    if perintah.get_version().is_some() {
+        perintah = perintah.disable_version_flag(true).arg(
+            Arg::new("version")
+                .short('V')
+                .long("version")
+                .action(ArgAction::Version)
+                .help(pesan::teks("bantuan.version"))
+                .help_heading(opsi)
+                .display_order(URUTAN_BANTUAN + 1),
+        );
+    }
+    if let Some(teks) = pesan::coba(&format!("{}.setelah", jalur)) {
+        perintah = perintah.after_help(teks);
+    }
+    perintah
+        .subcommand_help_heading(pesan::teks("bantuan.judul_perintah"))
+        .help_template(format!(
+            "{{before-help}}{{about-with-newline}}\n{} {{usage}}\n\n{{all-args}}{{after-help}}",
+            pesan::teks("bantuan.penggunaan")
+        ))
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
+    /// Konversi nilai dari satu satuan ke satuan lain
     Convert {
-        #[arg(long)]
+        /// Satuan asal
+        #[arg(long, value_name = "SATUAN")]
         from: String,
-        #[arg(long)]
+        /// Satuan tujuan
+        #[arg(long, value_name = "SATUAN")]
         to: String,
-        #[arg(long, allow_negative_numbers = true)]
+        /// Nilai yang dikonversi
+        // Nilai berlokal boleh berupa "-1 234,5" atau "−5"
+        #[arg(long, allow_hyphen_values = true)]
         value: String,
     },
+    /// Tampilkan daftar satuan yang didukung
     List,
+    /// Tampilkan riwayat konversi
     History,
+    /// Hitung ekspresi besaran, misal: eval 3 h 20 min + 45 min in hours
+    Eval {
+        /// Ekspresi, opsional diakhiri "in <satuan>"
+        #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        ekspresi: Vec<String>,
+        /// Satuan hasil (alternatif untuk "in <satuan>")
+        #[arg(long, value_name = "SATUAN")]
+        to: Option<String>,
+    },
+    /// Mode interaktif untuk konversi berulang
+    Repl,
+    /// Konversi massal per baris ("10 kg lbs", atau "10 kg" dengan --to) dari berkas atau stdin
+    Batch {
+        /// Berkas masukan; tanpa berkas atau "-" berarti stdin
+        #[arg(value_name = "BERKAS")]
+        input: Option<PathBuf>,
+        /// Satuan tujuan untuk semua baris
+        #[arg(long, value_name = "SATUAN")]
+        to: Option<String>,
+        /// Jangan catat konversi ke riwayat
+        #[arg(long)]
+        no_history: bool,
+    },
+    /// Konversi satu kolom CSV, misal: csv data.csv --column suhu --from F --to C
+    #[command(group(ArgGroup::new("asal").required(true).args(["from", "unit_column"])))]
+    Csv {
+        /// Berkas CSV masukan; tanpa berkas atau "-" berarti stdin
+        #[arg(value_name = "BERKAS")]
+        input: Option<PathBuf>,
+        /// Kolom nilai: nama di header atau indeks mulai dari 1
+        #[arg(long)]
+        column: String,
+        /// Satuan asal untuk semua baris
+        #[arg(long, value_name = "SATUAN")]
+        from: Option<String>,
+        /// Kolom (nama atau indeks) yang berisi satuan asal tiap baris
+        #[arg(long, value_name = "KOLOM")]
+        unit_column: Option<String>,
+        /// Satuan tujuan
+        #[arg(long, value_name = "SATUAN")]
+        to: String,
+        /// Tambahkan hasil sebagai kolom baru (nama opsional) alih-alih mengganti kolom asal
+        #[arg(long, value_name = "NAMA", num_args = 0..=1, default_missing_value = "")]
+        append: Option<String>,
+        /// Baris pertama adalah data, bukan header
+        #[arg(long)]
+        no_header: bool,
+        /// Karakter pemisah kolom
+        #[arg(long, default_value_t = ',')]
+        delimiter: char,
+        /// Berkas keluaran; bawaan stdout
+        #[arg(long, value_name = "BERKAS")]
+        out: Option<PathBuf>,
+    },
+    /// Cetak skrip pelengkapan shell, misal: unitconv completions bash > /etc/bash_completion.d/unitconv
+    Completions {
+        /// Shell tujuan
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+    /// Daftar ejaan satuan untuk skrip pelengkapan (satu per baris)
+    #[command(name = "__complete-units", hide = true)]
+    CompleteUnits {
+        /// Hanya satuan yang sekategori dengan satuan ini
+        #[arg(long, value_name = "SATUAN")]
+        from: Option<String>,
+        /// Awalan ejaan yang sedang diketik
+        #[arg(default_value = "", allow_hyphen_values = true)]
+        awalan: String,
+    },
 }
@@ -1,166 +1,176 @@
-use crate::models::{Kategori, Satuan};
-
-pub fn konversi(nilai: f64, dari: Satuan, ke: Satuan) -> Result<f64, String> {
-    // Validasi nilai numerik
-    if nilai.is_nan() || nilai.is_infinite() {
-        return Err("Nilai input tidak valid (NaN atau Infinity)".to_string());
-    }
-
-    if dari == ke {
-        return Ok(nilai);
-    }
-
-    if dari.kategori() != ke.kategori() {
-        return Err(format!(
-            "Tidak dapat mengonversi satuan yang berbeda kategori: [{}] {} -> [{}] {}",
-            dari.kategori().nama(),
-            dari.nama(),
-            ke.kategori().nama(),
-            ke.nama()
-        ));
-    }
+use crate::dimensi::Dimensi;
+use crate::models::Satuan;
+use crate::pesan;
+use crate::saran::urai_dengan_saran;
+use std::fmt;
+
+const NOL_MUTLAK_CELSIUS: f64 = -273.15;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum KonversiError {
+    // Teks nilai yang tidak dapat dibaca sebagai angka
+    NilaiTidakValid(String),
+    NilaiTidakHingga,
+    SatuanTidakDikenali {
+        peran: &'static str,
+        input: String,
+        saran: Option<String>,
+    },
+    // Ekspresi satuan yang salah tulis, misal "m/^2"
+    SatuanTidakValid {
+        peran: &'static str,
+        input: String,
+        detail: String,
+    },
+    DimensiBerbeda {
+        dari: String,
+        dimensi_dari: Dimensi,
+        ke: String,
+        dimensi_ke: Dimensi,
+    },
+    DiBawahNolMutlak {
+        nilai: f64,
+        simbol: String,
+    },
+    // Nilai masukan hingga tetapi hasilnya tidak (misal 1e308 km -> mm)
+    Overflow {
+        nilai: f64,
+        dari: String,
+        ke: String,
+    },
+}
 
-    // Validasi spesifik
-    if dari == Satuan::Kelvin && nilai < 0.0 {
-        return Err("Nilai Kelvin tidak boleh negatif (< 0)".to_string());
+impl KonversiError {
+    // Kode keluar proses per jenis kesalahan (1 umum, 2 dipakai clap untuk argumen salah)
+    pub fn kode_keluar(&self) -> i32 {
+        match self {
+            KonversiError::NilaiTidakValid(_) => 3,
+            KonversiError::NilaiTidakHingga => 4,
+            KonversiError::SatuanTidakDikenali { .. } | KonversiError::SatuanTidakValid { .. } => 5,
+            KonversiError::DimensiBerbeda { .. } => 6,
+            KonversiError::DiBawahNolMutlak { .. } => 7,
+            KonversiError::Overflow { .. } => 8,
+        }
     }
+}
 
-    match dari.kategori() {
-        Kategori::Suhu => Ok(konversi_suhu(nilai, dari, ke)),
-        Kategori::Panjang => Ok(konversi_panjang(nilai, dari, ke)),
-        Kategori::Berat => Ok(konversi_berat(nilai, dari, ke)),
-        Kategori::Volume => Ok(konversi_volume(nilai, dari, ke)),
-        Kategori::Waktu => Ok(konversi_waktu(nilai, dari, ke)),
-        Kategori::Kecepatan => Ok(konversi_kecepatan(nilai, dari, ke)),
-        Kategori::Data => Ok(konversi_data(nilai, dari, ke)),
+impl fmt::Display for KonversiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KonversiError::NilaiTidakValid(teks) => {
+                write!(f, "{}", pesan::format("galat.nilai_tidak_valid", &[teks]))
+            }
+            KonversiError::NilaiTidakHingga => write!(f, "{}", pesan::teks("galat.nilai_tidak_hingga")),
+            KonversiError::SatuanTidakDikenali { peran, input, saran } => {
+                write!(f, "{}", pesan::format("galat.satuan_tidak_dikenali", &[&nama_peran(peran), input]))?;
+                if let Some(saran) = saran {
+                    write!(f, "{}", pesan::format("galat.saran", &[saran]))?;
+                }
+                Ok(())
+            }
+            KonversiError::SatuanTidakValid { peran, input, detail } => write!(
+                f,
+                "{}",
+                pesan::format("galat.satuan_tidak_valid", &[&nama_peran(peran), input, detail])
+            ),
+            KonversiError::DimensiBerbeda { dari, dimensi_dari, ke, dimensi_ke } => write!(
+                f,
+                "{}",
+                pesan::format("galat.dimensi_berbeda", &[dimensi_dari, dari, dimensi_ke, ke])
+            ),
+            KonversiError::DiBawahNolMutlak { nilai, simbol } => {
+                write!(f, "{}", pesan::format("galat.nol_mutlak", &[nilai, simbol]))
+            }
+            KonversiError::Overflow { nilai, dari, ke } => write!(
+                f,
+                "{}",
+                pesan::format("galat.overflow", &[&format!("{:e}", nilai), dari, ke])
+            ),
+        }
     }
 }
 
-fn konversi_suhu(nilai: f64, dari: Satuan, ke: Satuan) -> f64 {
-    // Konversi ke Celsius terlebih dahulu
-    let celsius = match dari {
-        Satuan::Celsius => nilai,
-        Satuan::Fahrenheit => (nilai - 32.0) * 5.0 / 9.0,
-        Satuan::Kelvin => nilai - 273.15,
-        _ => unreachable!("Seharusnya satuan suhu"),
-    };
+impl std::error::Error for KonversiError {}
 
-    // Konversi dari Celsius ke target
-    match ke {
-        Satuan::Celsius => celsius,
-        Satuan::Fahrenheit => (celsius * 9.0 / 5.0) + 32.0,
-        Satuan::Kelvin => celsius + 273.15,
-        _ => unreachable!("Seharusnya satuan suhu"),
+// Peran ("asal", "tujuan", "kuantitas") dalam bahasa aktif
+fn nama_peran(peran: &str) -> &'static str {
+    match peran {
+        "asal" => pesan::teks("peran.asal"),
+        "tujuan" => pesan::teks("peran.tujuan"),
+        _ => pesan::teks("peran.kuantitas"),
     }
 }
 
-fn konversi_panjang(nilai: f64, dari: Satuan, ke: Satuan) -> f64 {
-    // Konversi ke Meter terlebih dahulu (Standar SI)
-    let meter = match dari {
-        Satuan::Cm => nilai / 100.0,
-        Satuan::Inch => nilai * 0.0254,
-        Satuan::Km => nilai * 1000.0,
-        Satuan::Miles => nilai * 1609.344,
-        _ => unreachable!("Seharusnya satuan panjang"),
-    };
-
-    match ke {
-        Satuan::Cm => meter * 100.0,
-        Satuan::Inch => meter / 0.0254,
-        Satuan::Km => meter / 1000.0,
-        Satuan::Miles => meter / 1609.344,
-        _ => unreachable!("Seharusnya satuan panjang"),
+// Membaca teks nilai dari argumen atau baris masukan; "NaN" dan "inf" ditolak di sini
+pub fn urai_nilai(teks: &str) -> Result<f64, KonversiError> {
+    let nilai: f64 = teks
+        .trim()
+        .parse()
+        .map_err(|_| KonversiError::NilaiTidakValid(teks.to_string()))?;
+    if !nilai.is_finite() {
+        return Err(KonversiError::NilaiTidakHingga);
     }
+    Ok(nilai)
 }
 
-fn konversi_berat(nilai: f64, dari: Satuan, ke: Satuan) -> f64 {
-    // Konversi ke Kilogram terlebih dahulu (Standar SI)
-    let kg = match dari {
-        Satuan::Kg => nilai,
-        Satuan::Gram => nilai / 1000.0,
-        Satuan::Lbs => nilai * 0.45359237,
-        Satuan::Ounce => nilai * 0.0283495,
-        _ => unreachable!("Seharusnya satuan berat"),
-    };
-
-    match ke {
-        Satuan::Kg => kg,
-        Satuan::Gram => kg * 1000.0,
-        Satuan::Lbs => kg / 0.45359237,
-        Satuan::Ounce => kg / 0.0283495,
-        _ => unreachable!("Seharusnya satuan berat"),
+pub fn konversi(nilai: f64, dari: &Satuan, ke: &Satuan) -> Result<f64, KonversiError> {
+    // Validasi nilai numerik
+    if nilai.is_nan() || nilai.is_infinite() {
+        return Err(KonversiError::NilaiTidakHingga);
     }
-}
 
-fn konversi_volume(nilai: f64, dari: Satuan, ke: Satuan) -> f64 {
-    // Konversi ke Liter terlebih dahulu
-    // 1 Gallon (US) = 3.785411784 L
-    let liter = match dari {
-        Satuan::Liter => nilai,
-        Satuan::Gallon => nilai * 3.785411784,
-        Satuan::Ml => nilai / 1000.0,
-        _ => unreachable!("Seharusnya satuan volume"),
-    };
+    if dari.setara(ke) {
+        return Ok(nilai);
+    }
 
-    match ke {
-        Satuan::Liter => liter,
-        Satuan::Gallon => liter / 3.785411784,
-        Satuan::Ml => liter * 1000.0,
-        _ => unreachable!("Seharusnya satuan volume"),
+    if dari.dimensi() != ke.dimensi() {
+        return Err(KonversiError::DimensiBerbeda {
+            dari: dari.nama().to_string(),
+            dimensi_dari: dari.dimensi(),
+            ke: ke.nama().to_string(),
+            dimensi_ke: ke.dimensi(),
+        });
     }
-}
 
-fn konversi_waktu(nilai: f64, dari: Satuan, ke: Satuan) -> f64 {
-    // Konversi ke Detik terlebih dahulu
-    let detik = match dari {
-        Satuan::Detik => nilai,
-        Satuan::Menit => nilai * 60.0,
-        Satuan::Jam => nilai * 3600.0,
-        _ => unreachable!("Seharusnya satuan waktu"),
-    };
+    // Semua satuan dikonversi lewat satuan SI koheren dimensinya (m, kg, m³, N, ...; suhu °C)
+    let nilai_dasar = dari.ke_dasar(nilai);
 
-    match ke {
-        Satuan::Detik => detik,
-        Satuan::Menit => detik / 60.0,
-        Satuan::Jam => detik / 3600.0,
-        _ => unreachable!("Seharusnya satuan waktu"),
+    // Validasi spesifik
+    if dari.dimensi() == Dimensi::SUHU && nilai_dasar < NOL_MUTLAK_CELSIUS {
+        return Err(KonversiError::DiBawahNolMutlak {
+            nilai,
+            simbol: dari.simbol().to_string(),
+        });
     }
-}
 
-fn konversi_kecepatan(nilai: f64, dari: Satuan, ke: Satuan) -> f64 {
-    // Konversi ke Meter per detik (m/s) terlebih dahulu
-    // 1 km/h = 1000m / 3600s = 1/3.6 m/s
-    // 1 mph = 1609.344m / 3600s = 0.44704 m/s
-    let ms = match dari {
-        Satuan::Ms => nilai,
-        Satuan::Kmh => nilai / 3.6,
-        Satuan::Mph => nilai * 0.44704,
-        _ => unreachable!("Seharusnya satuan kecepatan"),
+    // Tanpa offset, nilai cukup dikalikan sekali dengan rasio faktor yang sudah disederhanakan,
+    // sehingga 1 gal tetap 3.785411784 L dan tidak melewati pembulatan nilai dasar
+    let hasil = if dari.offset() == 0.0 && ke.offset() == 0.0 {
+        let rasio = dari.faktor_pecahan().bagi(ke.faktor_pecahan());
+        let hasil = nilai * rasio.pembilang() / rasio.penyebut();
+        // Hasil kali antara bisa melampaui f64 walau hasil akhirnya tidak; bagi dulu baru kalikan
+        if hasil.is_finite() {
+            hasil
+        } else {
+            nilai / rasio.penyebut() * rasio.pembilang()
+        }
+    } else {
+        ke.dari_dasar(nilai_dasar)
     };
-
-    match ke {
-        Satuan::Ms => ms,
-        Satuan::Kmh => ms * 3.6,
-        Satuan::Mph => ms / 0.44704,
-        _ => unreachable!("Seharusnya satuan kecepatan"),
+    if !hasil.is_finite() {
+        return Err(KonversiError::Overflow {
+            nilai,
+            dari: dari.simbol().to_string(),
+            ke: ke.simbol().to_string(),
+        });
     }
+    Ok(hasil)
 }
 
-fn konversi_data(nilai: f64, dari: Satuan, ke: Satuan) -> f64 {
-    // Konversi ke Byte terlebih dahulu (menggunakan 1024 / Binary prefixes)
-    let byte = match dari {
-        Satuan::Byte => nilai,
-        Satuan::KB => nilai * 1024.0,
-        Satuan::MB => nilai * 1024.0 * 1024.0,
-        Satuan::GB => nilai * 1024.0 * 1024.0 * 1024.0,
-        _ => unreachable!("Seharusnya satuan data"),
-    };
-
-    match ke {
-        Satuan::Byte => byte,
-        Satuan::KB => byte / 1024.0,
-        Satuan::MB => byte / (1024.0 * 1024.0),
-        Satuan::GB => byte / (1024.0 * 1024.0 * 1024.0),
-        _ => unreachable!("Seharusnya satuan data"),
-    }
+// Mengurai kedua nama satuan (dengan saran salah eja) lalu mengonversi
+pub fn konversi_teks(nilai: f64, dari: &str, ke: &str) -> Result<(Satuan, Satuan, f64), KonversiError> {
+    let asal = urai_dengan_saran("asal", dari)?;
+    let tujuan = urai_dengan_saran("tujuan", ke)?;
+    let hasil = konversi(nilai, &asal, &tujuan)?;
+    Ok((asal, tujuan, hasil))
 }
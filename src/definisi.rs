@@ -0,0 +1,98 @@
+use crate::eksak;
+use crate::kalkulator;
+use crate::models::{DefinisiSatuan, Kategori, Satuan, UnitRegistry};
+use crate::pesan;
+use num_traits::{Signed, ToPrimitive};
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Isi berkas definisi: daftar satuan di bawah kunci "satuan"
+#[derive(Deserialize)]
+struct BerkasDefinisi {
+    #[serde(default)]
+    satuan: Vec<DefinisiSatuan>,
+}
+
+// Lokasi bawaan: $XDG_CONFIG_HOME/unitconv/units.toml (atau ~/.config/unitconv/units.toml).
+// Jika units.toml tidak ada, units.json di direktori yang sama juga dicoba.
+pub fn lokasi_bawaan() -> Option<PathBuf> {
+    let dir_konfigurasi = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    let dir = dir_konfigurasi.join("unitconv");
+
+    ["units.toml", "units.json"]
+        .iter()
+        .map(|nama| dir.join(nama))
+        .find(|path| path.exists())
+}
+
+pub fn muat_berkas(path: &Path) -> Result<Vec<DefinisiSatuan>, String> {
+    let isi = fs::read_to_string(path)
+        .map_err(|e| pesan::format("definisi.gagal_baca", &[&path.display(), &e]))?;
+
+    let berkas: Result<BerkasDefinisi, String> = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str::<BerkasDefinisi>(&isi)
+            // Berkas JSON juga boleh berupa array satuan langsung
+            .or_else(|_| serde_json::from_str::<Vec<DefinisiSatuan>>(&isi).map(|satuan| BerkasDefinisi { satuan }))
+            .map_err(|e| e.to_string()),
+        _ => toml::from_str::<BerkasDefinisi>(&isi).map_err(|e| e.to_string()),
+    };
+
+    berkas
+        .map(|b| b.satuan)
+        .map_err(|e| pesan::format("definisi.format_tidak_valid", &[&path.display(), &e.trim_end()]))
+}
+
+// Registry bawaan ditambah satuan dari berkas. Berkas yang diminta eksplisit wajib ada,
+// sedangkan lokasi bawaan boleh tidak ada.
+pub fn siapkan_registry(berkas: Option<&Path>) -> Result<UnitRegistry, String> {
+    let mut registry = UnitRegistry::bawaan();
+
+    let path = match berkas {
+        Some(path) => path.to_path_buf(),
+        None => match lokasi_bawaan() {
+            Some(path) => path,
+            None => return Ok(registry),
+        },
+    };
+
+    let mut kesalahan = Vec::new();
+    for definisi in muat_berkas(&path)? {
+        if let Err(e) = registry.tambah(definisi) {
+            kesalahan.push(format!("  - {}", e));
+        }
+    }
+
+    if kesalahan.is_empty() {
+        Ok(registry)
+    } else {
+        Err(pesan::format("definisi.tidak_valid", &[&path.display(), &kesalahan.join("\n")]))
+    }
+}
+
+// Tekanan atmosfer acuan untuk psig/barg, misal "14.7 psi" atau "1013 hPa"; angka saja berarti Pa
+pub fn atur_atmosfer(registry: &mut UnitRegistry, teks: &str) -> Result<(), String> {
+    let galat = || pesan::format("definisi.atmosfer_tidak_valid", &[&teks]);
+    // Dibaca persis agar offset psig/barg pada --exact tepat sama dengan atmosfer acuan
+    let pa = match eksak::dari_teks(teks.trim()) {
+        Some(pa) => pa,
+        None => {
+            let (nilai, satuan) = kalkulator::pisahkan_teks_nilai(teks).ok_or_else(galat)?;
+            let nilai = eksak::dari_teks(nilai).ok_or_else(galat)?;
+            let satuan = registry.urai(satuan).map_err(|_| galat())?;
+            if satuan.kategori() != Some(Kategori::Tekanan) {
+                return Err(galat());
+            }
+            eksak::konversi(&nilai, &satuan, &Satuan::si(satuan.dimensi())).map_err(|_| galat())?
+        }
+    };
+    if !pa.is_positive() || !pa.to_f64().is_some_and(f64::is_finite) {
+        return Err(galat());
+    }
+    registry.atur_atmosfer(&pa);
+    Ok(())
+}
@@ -0,0 +1,78 @@
+// Backend desimal presisi sembarang (fitur "big-decimal"). Nilai dibaca langsung dari teks dan
+// faktor dari representasi desimal persisnya, sehingga 1 PB = 1000000000000000 B tanpa lewat f64.
+use crate::converter::KonversiError;
+use crate::dimensi::Dimensi;
+use crate::models::Satuan;
+use crate::saran::urai_dengan_saran;
+use bigdecimal::BigDecimal;
+use num_rational::BigRational;
+use std::str::FromStr;
+
+// Digit bermakna untuk hasil bagi yang tidak berhingga, misal 5/9
+const PRESISI: u64 = 50;
+
+// "1e30", "0.000000001", "-40"; NaN dan Infinity tidak diterima
+pub fn urai_nilai(teks: &str) -> Result<BigDecimal, KonversiError> {
+    let teks = teks.trim();
+    let tanpa_tanda = teks.trim_start_matches(['+', '-']).to_ascii_lowercase();
+    if matches!(tanpa_tanda.as_str(), "nan" | "inf" | "infinity") {
+        return Err(KonversiError::NilaiTidakHingga);
+    }
+    BigDecimal::from_str(teks).map_err(|_| KonversiError::NilaiTidakValid(teks.to_string()))
+}
+
+// f64 dari tabel satuan dibaca lewat representasi desimal terpendeknya (0.0254 tetap 0.0254)
+fn dari_f64(n: f64) -> BigDecimal {
+    BigDecimal::from_str(&format!("{:e}", n)).unwrap_or_default()
+}
+
+// Pembilang dan penyebut bulat dari pecahan persis faktor atau offset, tanpa lewat f64
+fn pecahan(persis: &BigRational) -> (BigDecimal, BigDecimal) {
+    let bulat = |n: &num_bigint::BigInt| BigDecimal::from_str(&n.to_string()).unwrap_or_default();
+    (bulat(persis.numer()), bulat(persis.denom()))
+}
+
+pub fn konversi(nilai: &BigDecimal, dari: &Satuan, ke: &Satuan) -> Result<BigDecimal, KonversiError> {
+    if dari.dimensi() != ke.dimensi() {
+        return Err(KonversiError::DimensiBerbeda {
+            dari: dari.nama().to_string(),
+            dimensi_dari: dari.dimensi(),
+            ke: ke.nama().to_string(),
+            dimensi_ke: ke.dimensi(),
+        });
+    }
+
+    let (pembilang_dari, penyebut_dari) = pecahan(dari.faktor_pecahan().persis());
+    let (pembilang_ke, penyebut_ke) = pecahan(ke.faktor_pecahan().persis());
+    // Offset tekanan ukur (atmosfer / faktor) tidak berhingga dalam desimal, jadi ikut sebagai pecahan
+    let (offset_dari, per_offset_dari) = pecahan(dari.offset_persis());
+    let (offset_ke, per_offset_ke) = pecahan(ke.offset_persis());
+
+    // Semua perkalian dilakukan dulu, pembagian sekali di akhir
+    let dasar_kali_penyebut = (nilai * &per_offset_dari + offset_dari) * &pembilang_dari;
+    let penyebut_dasar = &penyebut_dari * &per_offset_dari;
+    if dari.dimensi() == Dimensi::SUHU && &dasar_kali_penyebut / &penyebut_dasar < dari_f64(-273.15) {
+        return Err(KonversiError::DiBawahNolMutlak {
+            nilai: nilai.to_string().parse().unwrap_or(f64::NAN),
+            simbol: dari.simbol().to_string(),
+        });
+    }
+
+    let penyebut = penyebut_dasar * pembilang_ke;
+    let hasil = (dasar_kali_penyebut * penyebut_ke * &per_offset_ke - offset_ke * &penyebut) / (penyebut * per_offset_ke);
+    Ok(hasil.with_prec(PRESISI).normalized())
+}
+
+// Padanan converter::konversi_teks untuk backend desimal
+pub fn konversi_teks(nilai: &str, dari: &str, ke: &str) -> Result<(BigDecimal, Satuan, Satuan, BigDecimal), KonversiError> {
+    let nilai = urai_nilai(nilai)?;
+    let asal = urai_dengan_saran("asal", dari)?;
+    let tujuan = urai_dengan_saran("tujuan", ke)?;
+    let hasil = konversi(&nilai, &asal, &tujuan)?;
+    Ok((nilai, asal, tujuan, hasil))
+}
+
+// Selalu dalam notasi biasa, tanpa eksponen
+pub fn format(nilai: &BigDecimal) -> String {
+    nilai.normalized().to_plain_string()
+}
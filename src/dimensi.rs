@@ -0,0 +1,162 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::pesan;
+use std::fmt;
+
+// Urutan: panjang, massa, waktu, suhu, jumlah zat, arus, intensitas cahaya, informasi
+const SIMBOL: [&str; 8] = ["L", "M", "T", "Θ", "N", "I", "J", "D"];
+// Satuan SI koheren untuk tiap dimensi dasar (informasi dalam byte)
+const SIMBOL_SI: [&str; 8] = ["m", "kg", "s", "K", "mol", "A", "cd", "B"];
+
+// Vektor pangkat dimensi, misal kecepatan = L·T⁻¹
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Dimensi(pub [i8; 8]);
+
+impl Dimensi {
+    pub const TANPA: Dimensi = Dimensi([0, 0, 0, 0, 0, 0, 0, 0]);
+    pub const PANJANG: Dimensi = Dimensi([1, 0, 0, 0, 0, 0, 0, 0]);
+    pub const MASSA: Dimensi = Dimensi([0, 1, 0, 0, 0, 0, 0, 0]);
+    pub const WAKTU: Dimensi = Dimensi([0, 0, 1, 0, 0, 0, 0, 0]);
+    pub const SUHU: Dimensi = Dimensi([0, 0, 0, 1, 0, 0, 0, 0]);
+    pub const JUMLAH: Dimensi = Dimensi([0, 0, 0, 0, 1, 0, 0, 0]);
+    pub const ARUS: Dimensi = Dimensi([0, 0, 0, 0, 0, 1, 0, 0]);
+    pub const INTENSITAS: Dimensi = Dimensi([0, 0, 0, 0, 0, 0, 1, 0]);
+    pub const INFORMASI: Dimensi = Dimensi([0, 0, 0, 0, 0, 0, 0, 1]);
+
+    // Pangkat disimpan sebagai i8; None bila hasilnya di luar rentang (misal m^100 * m^100)
+    pub fn kali(self, lain: Dimensi) -> Option<Dimensi> {
+        let mut hasil = self;
+        for (a, b) in hasil.0.iter_mut().zip(lain.0) {
+            *a = a.checked_add(b)?;
+        }
+        Some(hasil)
+    }
+
+    pub fn bagi(self, lain: Dimensi) -> Option<Dimensi> {
+        self.kali(lain.pangkat(-1)?)
+    }
+
+    pub fn pangkat(self, n: i8) -> Option<Dimensi> {
+        let mut hasil = self;
+        for a in hasil.0.iter_mut() {
+            *a = a.checked_mul(n)?;
+        }
+        Some(hasil)
+    }
+
+    // Simbol satuan SI koheren, misal L·M·T⁻² -> "kg·m·s⁻²" (pangkat positif lebih dulu)
+    pub fn simbol_si(&self) -> String {
+        let urutan = [1, 0, 2, 3, 4, 5, 6, 7];
+        let positif = urutan.iter().filter(|&&i| self.0[i] > 0);
+        let negatif = urutan.iter().filter(|&&i| self.0[i] < 0);
+        let bagian: Vec<String> = positif
+            .chain(negatif)
+            .map(|&i| tulis_pangkat(SIMBOL_SI[i], self.0[i]))
+            .collect();
+        bagian.join("·")
+    }
+
+    // Menerima bentuk tampilan ("M·L·T⁻²") maupun ASCII ("M L T^-2")
+    pub fn dari_str(s: &str) -> Option<Dimensi> {
+        let s = s.trim();
+        if s.is_empty() || s == "1" {
+            return Some(Dimensi::TANPA);
+        }
+
+        let mut hasil = Dimensi::TANPA;
+        for bagian in s.split(|c: char| c == '·' || c == '*' || c.is_whitespace()).filter(|b| !b.is_empty()) {
+            let awal_pangkat = bagian
+                .find(|c: char| c == '^' || superskrip_ke_digit(c).is_some())
+                .unwrap_or(bagian.len());
+            let (simbol, pangkat) = bagian.split_at(awal_pangkat);
+            let posisi = match simbol {
+                "θ" => 3,
+                _ => SIMBOL.iter().position(|&x| x == simbol)?,
+            };
+            let pangkat: i8 = if pangkat.is_empty() {
+                1
+            } else if let Some(ascii) = pangkat.strip_prefix('^') {
+                ascii.parse().ok()?
+            } else {
+                pangkat.chars().map(superskrip_ke_digit).collect::<Option<String>>()?.parse().ok()?
+            };
+            hasil.0[posisi] = hasil.0[posisi].checked_add(pangkat)?;
+        }
+        Some(hasil)
+    }
+}
+
+pub(crate) fn superskrip_ke_digit(c: char) -> Option<char> {
+    match c {
+        '⁰' => Some('0'),
+        '¹' => Some('1'),
+        '²' => Some('2'),
+        '³' => Some('3'),
+        '⁴' => Some('4'),
+        '⁵' => Some('5'),
+        '⁶' => Some('6'),
+        '⁷' => Some('7'),
+        '⁸' => Some('8'),
+        '⁹' => Some('9'),
+        '⁻' => Some('-'),
+        _ => None,
+    }
+}
+
+fn tulis_pangkat(simbol: &str, n: i8) -> String {
+    if n == 1 {
+        simbol.to_string()
+    } else {
+        format!("{}{}", simbol, ke_superskrip(n))
+    }
+}
+
+fn ke_superskrip(n: i8) -> String {
+    n.to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            _ => '⁹',
+        })
+        .collect()
+}
+
+impl fmt::Display for Dimensi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bagian: Vec<String> = self
+            .0
+            .iter()
+            .zip(SIMBOL)
+            .filter(|(&n, _)| n != 0)
+            .map(|(&n, simbol)| tulis_pangkat(simbol, n))
+            .collect();
+
+        if bagian.is_empty() {
+            write!(f, "1")
+        } else {
+            write!(f, "{}", bagian.join("·"))
+        }
+    }
+}
+
+impl Serialize for Dimensi {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Dimensi {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Dimensi, D::Error> {
+        let teks = String::deserialize(deserializer)?;
+        Dimensi::dari_str(&teks)
+            .ok_or_else(|| serde::de::Error::custom(pesan::format("definisi.dimensi_tidak_valid", &[&teks])))
+    }
+}
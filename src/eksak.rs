@@ -0,0 +1,99 @@
+use crate::converter::KonversiError;
+use crate::models::Satuan;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+// Mode --exact: nilai dan faktor dijadikan pecahan bilangan bulat sehingga
+// 1 inch = 127/5000 m dan 1 lb = 45359237/100000000 kg tanpa galat floating point.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OpsiEksak {
+    // Jumlah digit desimal; None berarti pecahan persis (atau desimal jika berhingga)
+    pub digit: Option<usize>,
+}
+
+// Angka f64 dibaca dari representasi desimal terpendeknya: 0.1 -> 1/10, -273.15 -> -27315/100
+pub fn dari_f64(n: f64) -> BigRational {
+    dari_teks(&format!("{:e}", n)).unwrap_or_default()
+}
+
+// Desimal tertulis ("0.0254", "1e-6", "-273.15") menjadi pecahan persis tanpa lewat f64
+pub fn dari_teks(teks: &str) -> Option<BigRational> {
+    let (mantisa, eksponen) = match teks.split_once(['e', 'E']) {
+        Some((mantisa, eksponen)) => (mantisa, eksponen.parse::<i32>().ok()?),
+        None => (teks, 0),
+    };
+    let (bulat, pecahan) = mantisa.split_once('.').unwrap_or((mantisa, ""));
+    if bulat.trim_start_matches(['+', '-']).is_empty() && pecahan.is_empty() {
+        return None;
+    }
+    if !pecahan.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let digit: BigInt = format!("{}{}", bulat, pecahan).parse().ok()?;
+    let skala = eksponen.checked_sub(i32::try_from(pecahan.len()).ok()?)?;
+    let sepuluh = BigInt::from(10);
+    Some(if skala >= 0 {
+        BigRational::from_integer(digit * num_traits::pow(sepuluh, skala as usize))
+    } else {
+        BigRational::new(digit, num_traits::pow(sepuluh, skala.unsigned_abs() as usize))
+    })
+}
+
+// Sama dengan converter::konversi, tetapi dalam pecahan persis
+pub fn konversi(nilai: &BigRational, dari: &Satuan, ke: &Satuan) -> Result<BigRational, KonversiError> {
+    if dari.dimensi() != ke.dimensi() {
+        return Err(KonversiError::DimensiBerbeda {
+            dari: dari.nama().to_string(),
+            dimensi_dari: dari.dimensi(),
+            ke: ke.nama().to_string(),
+            dimensi_ke: ke.dimensi(),
+        });
+    }
+
+    let dasar = (nilai + dari.offset_persis()) * dari.faktor_pecahan().persis();
+    Ok(dasar / ke.faktor_pecahan().persis() - ke.offset_persis())
+}
+
+// Pecahan persis ("5/9"), desimal persis bila penyebutnya hanya berfaktor 2 dan 5 ("2.54"),
+// atau desimal dengan jumlah digit tertentu (dibulatkan menjauhi nol)
+pub fn format(nilai: &BigRational, digit: Option<usize>) -> String {
+    match digit {
+        Some(digit) => format_desimal(nilai, digit),
+        None => match digit_desimal_berhingga(nilai.denom()) {
+            Some(digit) => format_desimal(nilai, digit),
+            None => format!("{}/{}", nilai.numer(), nilai.denom()),
+        },
+    }
+}
+
+// Banyak digit desimal yang cukup untuk menuliskan 1/penyebut secara persis
+fn digit_desimal_berhingga(penyebut: &BigInt) -> Option<usize> {
+    let mut sisa = penyebut.clone();
+    let mut jumlah = [0usize; 2];
+    for (i, prima) in [2, 5].into_iter().enumerate() {
+        let prima = BigInt::from(prima);
+        while (&sisa % &prima).is_zero() {
+            sisa /= &prima;
+            jumlah[i] += 1;
+        }
+    }
+    sisa.is_one().then(|| jumlah[0].max(jumlah[1]))
+}
+
+fn format_desimal(nilai: &BigRational, digit: usize) -> String {
+    let skala = BigRational::from_integer(num_traits::pow(BigInt::from(10), digit));
+    let dibulatkan = (nilai * skala).round().to_integer();
+    let negatif = dibulatkan.is_negative();
+    let angka = dibulatkan.abs().to_string();
+
+    let angka = format!("{:0>lebar$}", angka, lebar = digit + 1);
+    let (bulat, pecahan) = angka.split_at(angka.len() - digit);
+    let tanda = if negatif { "-" } else { "" };
+    if digit == 0 {
+        format!("{}{}", tanda, bulat)
+    } else {
+        format!("{}{}.{}", tanda, bulat, pecahan)
+    }
+}
@@ -0,0 +1,272 @@
+use crate::dimensi::{superskrip_ke_digit, Dimensi};
+use crate::models::{Faktor, Satuan, UnitRegistry};
+use crate::pesan;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GalatSatuan {
+    // Bagian ekspresi yang tidak dikenali sebagai satuan
+    TidakDikenali(String),
+    Sintaks(String),
+}
+
+impl fmt::Display for GalatSatuan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GalatSatuan::TidakDikenali(nama) => write!(f, "{}", pesan::format("ekspresi.tidak_dikenali", &[nama])),
+            GalatSatuan::Sintaks(pesan) => write!(f, "{}", pesan),
+        }
+    }
+}
+
+impl std::error::Error for GalatSatuan {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Angka(f64),
+    Nama(String),
+    Kali,
+    Bagi,
+    Pangkat(i8),
+    Buka,
+    Tutup,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Angka(n) => write!(f, "{}", n),
+            Token::Nama(nama) => write!(f, "{}", nama),
+            Token::Kali => write!(f, "*"),
+            Token::Bagi => write!(f, "/"),
+            Token::Pangkat(n) => write!(f, "^{}", n),
+            Token::Buka => write!(f, "("),
+            Token::Tutup => write!(f, ")"),
+        }
+    }
+}
+
+// Satuan tunggal dicari langsung di registry; selain itu diurai sebagai ekspresi.
+// Di dalam ekspresi, satuan suhu bermakna selisih sehingga offset-nya diabaikan (J/(kg*°C)).
+pub fn urai(registry: &UnitRegistry, input: &str) -> Result<Satuan, GalatSatuan> {
+    let input = input.trim();
+    if let Some(satuan) = registry.cari(input) {
+        return Ok(satuan);
+    }
+
+    let token = pecah_token(input)?;
+    if token.is_empty() {
+        return Err(GalatSatuan::Sintaks(pesan::teks("ekspresi.kosong").to_string()));
+    }
+
+    let mut pengurai = Pengurai { registry, token, posisi: 0 };
+    let (faktor, dimensi) = pengurai.ekspresi()?;
+    if let Some(sisa) = pengurai.token.get(pengurai.posisi) {
+        return Err(GalatSatuan::Sintaks(pesan::format("ekspresi.token_tak_terduga", &[sisa, &input])));
+    }
+    if faktor.nilai() == 0.0 || !faktor.nilai().is_finite() {
+        return Err(GalatSatuan::Sintaks(pesan::format("ekspresi.faktor_tidak_valid", &[&input])));
+    }
+
+    Ok(Satuan::turunan(input, dimensi, faktor))
+}
+
+fn pecah_token(input: &str) -> Result<Vec<Token>, GalatSatuan> {
+    let mut hasil = Vec::new();
+    let mut karakter = input.chars().peekable();
+
+    while let Some(&c) = karakter.peek() {
+        match c {
+            _ if c.is_whitespace() => {
+                karakter.next();
+            }
+            '*' | '·' | '×' => {
+                karakter.next();
+                hasil.push(Token::Kali);
+            }
+            '/' => {
+                karakter.next();
+                hasil.push(Token::Bagi);
+            }
+            '(' => {
+                karakter.next();
+                hasil.push(Token::Buka);
+            }
+            ')' => {
+                karakter.next();
+                hasil.push(Token::Tutup);
+            }
+            '^' => {
+                karakter.next();
+                let mut teks = String::new();
+                while let Some(&d) = karakter.peek() {
+                    if d.is_ascii_digit() || (d == '-' && teks.is_empty()) {
+                        teks.push(d);
+                        karakter.next();
+                    } else {
+                        break;
+                    }
+                }
+                let pangkat = teks
+                    .parse()
+                    .map_err(|_| GalatSatuan::Sintaks(pesan::format("ekspresi.pangkat_tidak_valid", &[&input])))?;
+                hasil.push(Token::Pangkat(pangkat));
+            }
+            _ if superskrip_ke_digit(c).is_some() => {
+                let mut teks = String::new();
+                while let Some(d) = karakter.peek().and_then(|&d| superskrip_ke_digit(d)) {
+                    teks.push(d);
+                    karakter.next();
+                }
+                let pangkat = teks
+                    .parse()
+                    .map_err(|_| GalatSatuan::Sintaks(pesan::format("ekspresi.pangkat_tidak_valid", &[&input])))?;
+                hasil.push(Token::Pangkat(pangkat));
+            }
+            _ if c.is_ascii_digit() || c == '.' => {
+                let mut teks = String::new();
+                while let Some(&d) = karakter.peek() {
+                    if d.is_ascii_digit() || d == '.' {
+                        teks.push(d);
+                        karakter.next();
+                    } else {
+                        break;
+                    }
+                }
+                let angka = teks
+                    .parse()
+                    .map_err(|_| GalatSatuan::Sintaks(pesan::format("ekspresi.angka_tidak_valid", &[&teks, &input])))?;
+                hasil.push(Token::Angka(angka));
+            }
+            _ => {
+                let mut nama = String::new();
+                while let Some(&d) = karakter.peek() {
+                    if d.is_whitespace() || "*·×/()^".contains(d) || superskrip_ke_digit(d).is_some() {
+                        break;
+                    }
+                    nama.push(d);
+                    karakter.next();
+                }
+                hasil.push(Token::Nama(nama));
+            }
+        }
+    }
+    Ok(hasil)
+}
+
+struct Pengurai<'a> {
+    registry: &'a UnitRegistry,
+    token: Vec<Token>,
+    posisi: usize,
+}
+
+impl Pengurai<'_> {
+    fn lihat(&self) -> Option<&Token> {
+        self.token.get(self.posisi)
+    }
+
+    // ekspresi := suku (('*' | '/' | implisit) suku)*, dievaluasi dari kiri
+    fn ekspresi(&mut self) -> Result<(Faktor, Dimensi), GalatSatuan> {
+        let (mut faktor, mut dimensi) = self.suku()?;
+        loop {
+            match self.lihat() {
+                Some(Token::Kali) => {
+                    self.posisi += 1;
+                    let (f, d) = self.suku()?;
+                    faktor = faktor.kali(&f);
+                    dimensi = dimensi.kali(d).ok_or_else(pangkat_terlalu_besar)?;
+                }
+                Some(Token::Bagi) => {
+                    self.posisi += 1;
+                    let (f, d) = self.suku()?;
+                    faktor = faktor.bagi(&f);
+                    dimensi = dimensi.bagi(d).ok_or_else(pangkat_terlalu_besar)?;
+                }
+                Some(Token::Angka(_)) | Some(Token::Nama(_)) | Some(Token::Buka) => {
+                    let (f, d) = self.suku()?;
+                    faktor = faktor.kali(&f);
+                    dimensi = dimensi.kali(d).ok_or_else(pangkat_terlalu_besar)?;
+                }
+                _ => break,
+            }
+        }
+        Ok((faktor, dimensi))
+    }
+
+    // suku := angka? atom?, sehingga "100km" pada "L/100km" menjadi satu pembagi
+    fn suku(&mut self) -> Result<(Faktor, Dimensi), GalatSatuan> {
+        let mut faktor = Faktor::from(1.0);
+        let mut dimensi = Dimensi::TANPA;
+        let mut ada = false;
+
+        if let Some(Token::Angka(n)) = self.lihat() {
+            faktor = Faktor::from(*n);
+            self.posisi += 1;
+            ada = true;
+        }
+        if matches!(self.lihat(), Some(Token::Nama(_)) | Some(Token::Buka)) {
+            let (f, d) = self.atom()?;
+            faktor = faktor.kali(&f);
+            dimensi = d;
+            ada = true;
+        }
+
+        if ada {
+            Ok((faktor, dimensi))
+        } else {
+            Err(GalatSatuan::Sintaks(match self.lihat() {
+                Some(token) => pesan::format("ekspresi.diharapkan_satuan", &[token]),
+                None => pesan::teks("ekspresi.berakhir_terlalu_cepat").to_string(),
+            }))
+        }
+    }
+
+    // atom := nama | '(' ekspresi ')', diikuti pangkat opsional
+    fn atom(&mut self) -> Result<(Faktor, Dimensi), GalatSatuan> {
+        let (faktor, dimensi) = match self.token.get(self.posisi).cloned() {
+            Some(Token::Nama(nama)) => {
+                self.posisi += 1;
+                match self.registry.cari(&nama) {
+                    Some(satuan) => (satuan.faktor_pecahan().clone(), satuan.dimensi()),
+                    // Pangkat tanpa tanda: "mm2" = mm^2, "cm3" = cm^3
+                    None => {
+                        let (satuan, pangkat) = pangkat_menempel(&nama)
+                            .and_then(|(dasar, n)| Some((self.registry.cari(dasar)?, n)))
+                            .ok_or(GalatSatuan::TidakDikenali(nama))?;
+                        let dimensi = satuan.dimensi().pangkat(pangkat).ok_or_else(pangkat_terlalu_besar)?;
+                        (satuan.faktor_pecahan().pangkat(pangkat), dimensi)
+                    }
+                }
+            }
+            Some(Token::Buka) => {
+                self.posisi += 1;
+                let hasil = self.ekspresi()?;
+                if self.lihat() != Some(&Token::Tutup) {
+                    return Err(GalatSatuan::Sintaks(pesan::teks("ekspresi.kurung_tutup").to_string()));
+                }
+                self.posisi += 1;
+                hasil
+            }
+            _ => return Err(GalatSatuan::Sintaks(pesan::teks("ekspresi.diharapkan_nama").to_string())),
+        };
+
+        if let Some(&Token::Pangkat(n)) = self.lihat() {
+            self.posisi += 1;
+            return Ok((faktor.pangkat(n), dimensi.pangkat(n).ok_or_else(pangkat_terlalu_besar)?));
+        }
+        Ok((faktor, dimensi))
+    }
+}
+
+fn pangkat_terlalu_besar() -> GalatSatuan {
+    GalatSatuan::Sintaks(pesan::teks("ekspresi.pangkat_terlalu_besar").to_string())
+}
+
+// Nama yang diakhiri satu digit pangkat, misal "mm2" -> ("mm", 2)
+fn pangkat_menempel(nama: &str) -> Option<(&str, i8)> {
+    let dasar = nama.strip_suffix(|c: char| ('2'..='9').contains(&c))?;
+    if dasar.is_empty() || dasar.ends_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((dasar, nama[dasar.len()..].parse().ok()?))
+}
@@ -1,4 +1,6 @@
 use crate::models::{CatatanKonversi, Satuan};
+use crate::pembulatan::FormatAngka;
+use crate::pesan;
 use std::fs::{self, File, OpenOptions};
 use std::io::BufReader;
 use std::path::Path;
@@ -11,6 +13,17 @@ pub fn simpan_riwayat(dari: &str, ke: &str, nilai_input: f64, nilai_output: Opti
         nilai_output,
         pesan_error,
     };
+    simpan_banyak_riwayat(vec![catatan]);
+}
+
+// Menambahkan banyak catatan sekaligus dengan satu kali baca-tulis berkas (dipakai mode batch)
+pub fn simpan_banyak_riwayat(mut catatan_baru: Vec<CatatanKonversi>) {
+    // JSON tidak punya Infinity/NaN; serde_json menulisnya sebagai null sehingga berkas tidak
+    // terbaca lagi. Nilai di luar rentang f64 (misal 1e400 dari --big-decimal) tidak dicatat.
+    catatan_baru.retain(|c| c.nilai_input.is_finite() && c.nilai_output.is_none_or(f64::is_finite));
+    if catatan_baru.is_empty() {
+        return;
+    }
 
     let path = "conversion.json";
     let mut daftar_catatan: Vec<CatatanKonversi> = Vec::new();
@@ -26,37 +39,50 @@ pub fn simpan_riwayat(dari: &str, ke: &str, nilai_input: f64, nilai_output: Opti
                     Err(_) => {
                         // JSON korup ditemukan. Backup dan mulai baru.
                         let backup_path = "conversion.json.bak";
-                        eprintln!("Peringatan: File riwayat korup. Membuat cadangan ke '{}' dan memulai riwayat baru.", backup_path);
+                        let peringatan = pesan::format("riwayat.korup_dicadangkan", &[&backup_path]);
+                        eprintln!("{}", pesan::format("umum.peringatan", &[&peringatan]));
                         if let Err(e) = fs::rename(path, backup_path) {
-                            eprintln!("Error: Gagal membuat cadangan riwayat: {}", e);
+                            eprintln!("{}", pesan::galat(pesan::format("riwayat.gagal_cadangan", &[&e])));
                         }
                     }
                 }
             }
             Err(e) => {
-                eprintln!("Peringatan: Gagal membaca file riwayat: {}", e);
+                let peringatan = pesan::format("riwayat.gagal_baca", &[&e]);
+                eprintln!("{}", pesan::format("umum.peringatan", &[&peringatan]));
             }
         }
     }
 
-    daftar_catatan.push(catatan);
+    daftar_catatan.extend(catatan_baru);
 
     match OpenOptions::new().write(true).create(true).truncate(true).open(path) {
         Ok(file) => {
             if let Err(e) = serde_json::to_writer_pretty(file, &daftar_catatan) {
-                eprintln!("Error: Gagal menyimpan riwayat: {}", e);
+                eprintln!("{}", pesan::galat(pesan::format("riwayat.gagal_simpan", &[&e])));
             }
         }
         Err(e) => {
-            eprintln!("Error: Gagal membuka file riwayat untuk ditulis: {}", e);
+            eprintln!("{}", pesan::galat(pesan::format("riwayat.gagal_buka_tulis", &[&e])));
         }
     }
 }
 
-pub fn tampilkan_riwayat() {
+// Riwayat mentah untuk keluaran JSON/TSV; berkas yang belum ada berarti riwayat kosong
+pub fn baca_riwayat() -> Result<Vec<CatatanKonversi>, String> {
+    let path = "conversion.json";
+    if !Path::new(path).exists() {
+        return Ok(Vec::new());
+    }
+    let file = File::open(path).map_err(|e| pesan::format("riwayat.gagal_buka", &[&e]))?;
+    serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| pesan::format("riwayat.korup", &[&e]))
+}
+
+pub fn tampilkan_riwayat(angka: FormatAngka) {
     let path = "conversion.json";
     if !Path::new(path).exists() {
-        println!("Belum ada riwayat konversi.");
+        println!("{}", pesan::teks("riwayat.belum_ada"));
         return;
     }
 
@@ -66,43 +92,43 @@ pub fn tampilkan_riwayat() {
             match serde_json::from_reader::<_, Vec<CatatanKonversi>>(reader) {
                 Ok(daftar_catatan) => {
                     if daftar_catatan.is_empty() {
-                        println!("Riwayat konversi kosong.");
+                        println!("{}", pesan::teks("riwayat.kosong"));
                         return;
                     }
-                    println!("Riwayat Konversi:");
+                    println!("{}", pesan::teks("riwayat.judul"));
                     for (i, catatan) in daftar_catatan.iter().enumerate() {
                         if let Some(error) = &catatan.pesan_error {
+                            let nilai_input = angka.lokal.format(catatan.nilai_input);
                             println!(
-                                "{}. [GAGAL] {} {} -> {} (Error: {})",
-                                i + 1,
-                                catatan.nilai_input,
-                                catatan.satuan_asal,
-                                catatan.satuan_tujuan,
-                                error
+                                "{}",
+                                pesan::format(
+                                    "riwayat.baris_gagal",
+                                    &[&(i + 1), &nilai_input, &catatan.satuan_asal, &catatan.satuan_tujuan, error]
+                                )
                             );
                         } else if let Some(output) = catatan.nilai_output {
                             // Coba dapatkan simbol jika memungkinkan, jika tidak gunakan string satuan mentah
-                            let simbol_asal = Satuan::dari_str(&catatan.satuan_asal).map(|u| u.simbol()).unwrap_or(&catatan.satuan_asal);
-                            let simbol_tujuan = Satuan::dari_str(&catatan.satuan_tujuan).map(|u| u.simbol()).unwrap_or(&catatan.satuan_tujuan);
+                            let simbol_asal = Satuan::dari_str(&catatan.satuan_asal).map(|u| u.simbol().to_string()).unwrap_or(catatan.satuan_asal.clone());
+                            let simbol_tujuan = Satuan::dari_str(&catatan.satuan_tujuan).map(|u| u.simbol().to_string()).unwrap_or(catatan.satuan_tujuan.clone());
         
                             println!(
                                 "{}. {} {} = {} {}",
                                 i + 1,
-                                catatan.nilai_input,
+                                angka.lokal.format(catatan.nilai_input),
                                 simbol_asal,
-                                output,
+                                angka.untuk_input(catatan.nilai_input).format(output),
                                 simbol_tujuan
                             );
                         }
                     }
                 }
                 Err(e) => {
-                    println!("Error: Gagal membaca format data riwayat (file mungkin korup): {}", e);
+                    println!("{}", pesan::galat(pesan::format("riwayat.korup", &[&e])));
                 }
             }
         }
         Err(e) => {
-            println!("Error: Gagal membuka file riwayat: {}", e);
+            println!("{}", pesan::galat(pesan::format("riwayat.gagal_buka", &[&e])));
         }
     }
 }
@@ -0,0 +1,500 @@
+use crate::awalan::{JenisAwalan, AWALAN};
+use crate::dimensi::Dimensi;
+use crate::models::{Satuan, UnitRegistry};
+use crate::pesan;
+use crate::saran;
+use std::fmt;
+
+// Besaran antara selama evaluasi: nilai dalam satuan SI koheren beserta dimensinya
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Besaran {
+    nilai: f64,
+    dimensi: Dimensi,
+    // Nilai dasar titik nol skala bergeser (°F, K) bila besaran ini suhu pada skala itu.
+    // Suhu seperti itu adalah titik, bukan selisih, sehingga tidak dapat dijumlah atau dikali.
+    titik_nol: Option<f64>,
+}
+
+impl Besaran {
+    fn baru(nilai: f64, dimensi: Dimensi) -> Besaran {
+        Besaran { nilai, dimensi, titik_nol: None }
+    }
+
+    fn tanpa_titik_nol(self) -> Result<Besaran, String> {
+        match self.titik_nol {
+            Some(_) => Err(pesan::teks("kalkulator.suhu_bergeser").to_string()),
+            None => Ok(self),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HasilEvaluasi {
+    pub nilai: f64,
+    pub satuan: Option<Satuan>,
+    // Catatan tafsiran ejaan ambigu di ekspresi dan satuan tujuan, untuk dicetak pemanggil
+    pub catatan: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Angka(f64),
+    Satuan(String),
+    Tambah,
+    Kurang,
+    Kali,
+    Bagi,
+    Pangkat,
+    Buka,
+    Tutup,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Angka(n) => write!(f, "{}", n),
+            Token::Satuan(nama) => write!(f, "{}", nama),
+            Token::Tambah => write!(f, "+"),
+            Token::Kurang => write!(f, "-"),
+            Token::Kali => write!(f, "*"),
+            Token::Bagi => write!(f, "/"),
+            Token::Pangkat => write!(f, "^"),
+            Token::Buka => write!(f, "("),
+            Token::Tutup => write!(f, ")"),
+        }
+    }
+}
+
+// Kata penanda satuan hasil: "... in mph", "... ke km", "... -> h"
+const KATA_TUJUAN: &[&str] = &["in", "to", "ke", "dalam", "->"];
+
+// Memisahkan "120 km / 1.5 h in mph" menjadi ekspresi dan satuan tujuan (jika ada)
+pub fn pisahkan_tujuan(input: &str) -> (&str, Option<&str>) {
+    let kata: Vec<(usize, &str)> = input
+        .split_whitespace()
+        .map(|k| (k.as_ptr() as usize - input.as_ptr() as usize, k))
+        .collect();
+
+    for (indeks, &(posisi, k)) in kata.iter().enumerate().rev() {
+        if KATA_TUJUAN.iter().any(|t| t.eq_ignore_ascii_case(k)) {
+            let tujuan = input[posisi + k.len()..].trim();
+            if tujuan.is_empty() {
+                continue;
+            }
+            // "10 in cm": "in" tepat setelah angka adalah satuan inci dari angka itu
+            let setelah_angka = indeks > 0 && kata[indeks - 1].1.parse::<f64>().is_ok();
+            if setelah_angka && k.eq_ignore_ascii_case("in") {
+                return (input[..posisi + k.len()].trim(), Some(tujuan));
+            }
+            return (input[..posisi].trim(), Some(tujuan));
+        }
+    }
+    (input.trim(), None)
+}
+
+// Memisahkan angka di depan dari satuannya: "10kg" -> (10, "kg"), "-40 °F" -> (-40, "°F")
+pub fn pisahkan_nilai_satuan(input: &str) -> Option<(f64, &str)> {
+    let (nilai, satuan) = pisahkan_teks_nilai(input)?;
+    Some((nilai.parse().ok()?, satuan))
+}
+
+// Seperti pisahkan_nilai_satuan, tetapi angkanya tetap berupa teks ("1e30", "0.1")
+// agar dapat dibaca tanpa f64, misal oleh backend desimal besar
+pub fn pisahkan_teks_nilai(input: &str) -> Option<(&str, &str)> {
+    let input = input.trim();
+    let mut akhir_angka = 0;
+    let mut sebelumnya = None;
+    for (i, c) in input.char_indices() {
+        // Koma diikuti digit ikut terbaca ("1.234,5kg"); penafsirannya diserahkan ke lokal
+        let bagian_angka = c.is_ascii_digit()
+            || c == '.'
+            || (c == ',' && input[i + 1..].starts_with(|d: char| d.is_ascii_digit()))
+            || (c == '−' && i == 0)
+            || ((c == '-' || c == '+') && (i == 0 || matches!(sebelumnya, Some('e') | Some('E'))))
+            || ((c == 'e' || c == 'E') && input[i + 1..].starts_with(|d: char| d.is_ascii_digit() || d == '-' || d == '+'));
+        if !bagian_angka {
+            break;
+        }
+        akhir_angka = i + c.len_utf8();
+        sebelumnya = Some(c);
+    }
+
+    let nilai = &input[..akhir_angka];
+    let satuan = input[akhir_angka..].trim();
+    if nilai.is_empty() || satuan.is_empty() {
+        return None;
+    }
+    Some((nilai, satuan))
+}
+
+// Kueri bebas untuk konversi: "10kg lbs", "5.5 miles in km", "98.6F to C", "-40°F C".
+// Menghasilkan (teks nilai, satuan asal, satuan tujuan).
+pub fn pisahkan_kueri(input: &str) -> Option<(&str, &str, &str)> {
+    // Bentuk dengan kata penanda, termasuk "10 in cm" yang "in"-nya inci
+    if let (kiri, Some(tujuan)) = pisahkan_tujuan(input) {
+        if let Some((nilai, asal)) = pisahkan_teks_nilai(kiri) {
+            return Some((nilai, asal, tujuan));
+        }
+    }
+
+    // Bentuk tanpa penanda: kata terakhir adalah satuan tujuan
+    let (nilai, sisa) = pisahkan_teks_nilai(input)?;
+    let (asal, tujuan) = sisa.rsplit_once(char::is_whitespace)?;
+    let asal = asal.trim();
+    if asal.is_empty() {
+        return None;
+    }
+    Some((nilai, asal, tujuan))
+}
+
+// Evaluasi ekspresi aritmetika atas besaran, misal "3 h 20 min + 45 min" atau "120 km / 1.5 h".
+// Tanpa satuan tujuan, hasil ditampilkan dalam satuan yang paling pas.
+pub fn evaluasi(registry: &UnitRegistry, ekspresi: &str, tujuan: Option<&str>) -> Result<HasilEvaluasi, String> {
+    let token = pecah_token(ekspresi)?;
+    if token.is_empty() {
+        return Err(pesan::teks("kalkulator.ekspresi_kosong").to_string());
+    }
+
+    let mut pengurai = Pengurai { registry, token, posisi: 0, dipakai: Vec::new(), catatan: Vec::new() };
+    let hasil = pengurai.jumlah()?;
+    if let Some(sisa) = pengurai.lihat() {
+        return Err(pesan::format("kalkulator.token_tak_terduga_pada", &[sisa, &ekspresi]));
+    }
+    if hasil.nilai.is_nan() || hasil.nilai.is_infinite() {
+        return Err(pesan::teks("kalkulator.hasil_tidak_hingga").to_string());
+    }
+
+    let mut catatan = pengurai.catatan;
+    match tujuan {
+        Some(tujuan) => {
+            catatan.extend(saran::catatan_tafsiran(registry, tujuan));
+            let satuan = registry.urai(tujuan).map_err(|e| e.to_string())?;
+            if satuan.dimensi() != hasil.dimensi {
+                return Err(pesan::format(
+                    "kalkulator.dimensi_tujuan",
+                    &[&hasil.dimensi, &tujuan, &satuan.dimensi()],
+                ));
+            }
+            let nilai = satuan.dari_dasar(hasil.nilai);
+            Ok(HasilEvaluasi { nilai, satuan: Some(satuan), catatan })
+        }
+        None if hasil.dimensi == Dimensi::TANPA => Ok(HasilEvaluasi { nilai: hasil.nilai, satuan: None, catatan }),
+        None => {
+            let satuan = satuan_terbaik(registry, hasil.nilai, hasil.dimensi, &pengurai.dipakai);
+            Ok(HasilEvaluasi { nilai: satuan.dari_dasar(hasil.nilai), satuan: Some(satuan), catatan })
+        }
+    }
+}
+
+// Satuan dengan nilai paling "enak dibaca": nilai mutlak terkecil yang masih >= 1.
+// Satuan yang dipakai di ekspresi didahulukan ("3 h 20 min" tetap dalam jam); jika tidak ada,
+// dipakai satuan SI koheren dengan awalan kelipatan 10³ (ditambah menit/jam untuk waktu).
+pub fn satuan_terbaik(registry: &UnitRegistry, nilai_dasar: f64, dimensi: Dimensi, dipakai: &[Satuan]) -> Satuan {
+    let mut kandidat: Vec<Satuan> = dipakai.iter().filter(|s| s.dimensi() == dimensi).cloned().collect();
+
+    if kandidat.is_empty() {
+        let koheren = |satuan: &Satuan| satuan.faktor() == 1.0 && satuan.offset() == 0.0;
+        // Jika satuan koheren dapat diberi awalan (J, W, Pa), awalan satuan lain (kcal, kWh, mbar)
+        // tidak dipakai; gram dan liter tetap dipakai karena kg dan m³ tidak berawalan
+        let awalan_koheren = registry
+            .semua()
+            .iter()
+            .any(|s| s.dimensi() == dimensi && koheren(s) && s.awalan() != JenisAwalan::Tidak);
+        for satuan in registry.semua().iter().filter(|s| s.dimensi() == dimensi) {
+            if koheren(satuan) || dimensi == Dimensi::WAKTU {
+                kandidat.push(satuan.clone());
+            }
+            if awalan_koheren && !koheren(satuan) {
+                continue;
+            }
+            for awalan in AWALAN.iter().filter(|a| satuan.awalan().menerima(a)) {
+                let langkah = awalan.faktor.log10().round() as i32;
+                if awalan.biner || langkah % 3 != 0 || (dimensi == Dimensi::WAKTU && langkah > 0) {
+                    continue;
+                }
+                let ejaan = format!("{}{}", awalan.simbol_utama(), satuan.simbol());
+                if let Some(berawalan) = registry.cari(&ejaan) {
+                    kandidat.push(berawalan);
+                }
+            }
+        }
+    }
+
+    // Suhu dinyatakan dalam satuan tanpa offset bila tidak ada satuan dari ekspresi
+    if dimensi == Dimensi::SUHU && dipakai.iter().all(|s| s.dimensi() != dimensi) {
+        kandidat.retain(|s| s.offset() == 0.0);
+    }
+
+    let mut terbaik: Option<(f64, Satuan)> = None;
+    for satuan in kandidat {
+        let nilai = satuan.dari_dasar(nilai_dasar).abs();
+        let lebih_baik = match &terbaik {
+            None => true,
+            Some((nilai_terbaik, _)) => {
+                if nilai >= 1.0 && *nilai_terbaik >= 1.0 {
+                    nilai < *nilai_terbaik
+                } else {
+                    nilai > *nilai_terbaik && *nilai_terbaik < 1.0
+                }
+            }
+        };
+        if lebih_baik {
+            terbaik = Some((nilai, satuan));
+        }
+    }
+
+    terbaik
+        .map(|(_, satuan)| satuan)
+        .unwrap_or_else(|| Satuan::si(dimensi))
+}
+
+fn pecah_token(input: &str) -> Result<Vec<Token>, String> {
+    let mut hasil = Vec::new();
+    let karakter: Vec<char> = input.chars().collect();
+    let mut i = 0;
+
+    while i < karakter.len() {
+        let c = karakter[i];
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '+' => {
+                hasil.push(Token::Tambah);
+                i += 1;
+            }
+            '-' | '−' => {
+                hasil.push(Token::Kurang);
+                i += 1;
+            }
+            '*' | '×' | '·' => {
+                hasil.push(Token::Kali);
+                i += 1;
+            }
+            '/' | '÷' => {
+                hasil.push(Token::Bagi);
+                i += 1;
+            }
+            '^' => {
+                hasil.push(Token::Pangkat);
+                i += 1;
+            }
+            '(' => {
+                hasil.push(Token::Buka);
+                i += 1;
+            }
+            ')' => {
+                hasil.push(Token::Tutup);
+                i += 1;
+            }
+            _ if c.is_ascii_digit() || c == '.' => {
+                let awal = i;
+                while i < karakter.len() && (karakter[i].is_ascii_digit() || karakter[i] == '.') {
+                    i += 1;
+                }
+                // Notasi ilmiah: 1.5e3, 2e-6
+                if i + 1 < karakter.len() && (karakter[i] == 'e' || karakter[i] == 'E') {
+                    let mut j = i + 1;
+                    if j < karakter.len() && (karakter[j] == '-' || karakter[j] == '+') {
+                        j += 1;
+                    }
+                    if j < karakter.len() && karakter[j].is_ascii_digit() {
+                        i = j;
+                        while i < karakter.len() && karakter[i].is_ascii_digit() {
+                            i += 1;
+                        }
+                    }
+                }
+                let teks: String = karakter[awal..i].iter().collect();
+                let angka = teks
+                    .parse()
+                    .map_err(|_| pesan::format("kalkulator.angka_tidak_valid", &[&teks]))?;
+                hasil.push(Token::Angka(angka));
+            }
+            _ => {
+                // Satuan: rangkaian karakter tanpa spasi. "/", "*" dan "^" di dalamnya
+                // termasuk satuan ("km/h", "m/s^2"); dengan spasi menjadi operator.
+                let awal = i;
+                while i < karakter.len() {
+                    let d = karakter[i];
+                    let setelah_pangkat = i > awal && karakter[i - 1] == '^';
+                    if d.is_whitespace() || "+()".contains(d) || (d == '-' && !setelah_pangkat) {
+                        break;
+                    }
+                    i += 1;
+                }
+                let teks: String = karakter[awal..i].iter().collect();
+                hasil.push(Token::Satuan(teks));
+            }
+        }
+    }
+    Ok(hasil)
+}
+
+struct Pengurai<'a> {
+    registry: &'a UnitRegistry,
+    token: Vec<Token>,
+    posisi: usize,
+    // Satuan yang muncul di ekspresi, untuk memilih satuan hasil
+    dipakai: Vec<Satuan>,
+    catatan: Vec<String>,
+}
+
+impl Pengurai<'_> {
+    fn lihat(&self) -> Option<&Token> {
+        self.token.get(self.posisi)
+    }
+
+    fn ambil(&mut self) -> Option<Token> {
+        let token = self.token.get(self.posisi).cloned();
+        self.posisi += 1;
+        token
+    }
+
+    // jumlah := kali (('+' | '-') kali)*
+    fn jumlah(&mut self) -> Result<Besaran, String> {
+        let mut kiri = self.kali()?;
+        while let Some(op) = self.lihat().cloned() {
+            if op != Token::Tambah && op != Token::Kurang {
+                break;
+            }
+            self.posisi += 1;
+            let kanan = self.kali()?;
+            kiri = tambahkan(kiri, kanan, op == Token::Kurang)?;
+        }
+        Ok(kiri)
+    }
+
+    // kali := negasi (('*' | '/') negasi)*
+    fn kali(&mut self) -> Result<Besaran, String> {
+        let mut kiri = self.negasi()?;
+        while let Some(op) = self.lihat().cloned() {
+            let kanan = match op {
+                Token::Kali | Token::Bagi => {
+                    self.posisi += 1;
+                    self.negasi()?.tanpa_titik_nol()?
+                }
+                _ => break,
+            };
+            kiri = kiri.tanpa_titik_nol()?;
+            kiri = if op == Token::Kali {
+                Besaran::baru(kiri.nilai * kanan.nilai, dimensi_hasil(kiri.dimensi.kali(kanan.dimensi))?)
+            } else {
+                if kanan.nilai == 0.0 {
+                    return Err(pesan::teks("kalkulator.bagi_nol").to_string());
+                }
+                Besaran::baru(kiri.nilai / kanan.nilai, dimensi_hasil(kiri.dimensi.bagi(kanan.dimensi))?)
+            };
+        }
+        Ok(kiri)
+    }
+
+    // negasi := '-' negasi | pangkat; seperti lazimnya -2^2 = -(2^2)
+    fn negasi(&mut self) -> Result<Besaran, String> {
+        if self.lihat() == Some(&Token::Kurang) {
+            self.posisi += 1;
+            let besaran = self.negasi()?;
+            // -40 °F dinegasikan pada skala °F-nya sendiri, bukan pada nilai dasar °C
+            let nilai = match besaran.titik_nol {
+                Some(nol) => 2.0 * nol - besaran.nilai,
+                None => -besaran.nilai,
+            };
+            return Ok(Besaran { nilai, ..besaran });
+        }
+        self.pangkat()
+    }
+
+    // pangkat := primer ('^' negasi)?, asosiatif kanan: 2^3^2 = 2^(3^2).
+    // Pangkat harus tanpa dimensi dan bulat bila basisnya berdimensi.
+    fn pangkat(&mut self) -> Result<Besaran, String> {
+        let basis = self.primer()?;
+        if self.lihat() != Some(&Token::Pangkat) {
+            return Ok(basis);
+        }
+        self.posisi += 1;
+        let basis = basis.tanpa_titik_nol()?;
+        let eksponen = self.negasi()?;
+        if eksponen.dimensi != Dimensi::TANPA {
+            return Err(pesan::format("kalkulator.pangkat_bersatuan", &[&eksponen.dimensi]));
+        }
+        if basis.dimensi == Dimensi::TANPA {
+            return Ok(Besaran::baru(basis.nilai.powf(eksponen.nilai), Dimensi::TANPA));
+        }
+        if eksponen.nilai.fract() != 0.0 {
+            return Err(pesan::format("kalkulator.pangkat_bukan_bulat", &[&eksponen.nilai]));
+        }
+        let n = i8::try_from(eksponen.nilai as i64).map_err(|_| pesan::teks("kalkulator.pangkat_terlalu_besar").to_string())?;
+        Ok(Besaran::baru(basis.nilai.powi(n as i32), dimensi_hasil(basis.dimensi.pangkat(n))?))
+    }
+
+    // primer := besaran (besaran)* | '(' jumlah ')' satuan?
+    // Besaran berurutan tanpa operator dijumlahkan: "3 h 20 min" = 3 h + 20 min
+    fn primer(&mut self) -> Result<Besaran, String> {
+        match self.ambil() {
+            Some(Token::Angka(n)) => {
+                let mut hasil = self.lampirkan_satuan(n)?;
+                while let (Some(Token::Angka(_)), Some(Token::Satuan(_))) =
+                    (self.token.get(self.posisi), self.token.get(self.posisi + 1))
+                {
+                    let Some(Token::Angka(m)) = self.ambil() else { unreachable!() };
+                    let berikut = self.lampirkan_satuan(m)?;
+                    hasil = tambahkan(hasil, berikut, false)?;
+                }
+                Ok(hasil)
+            }
+            Some(Token::Buka) => {
+                let isi = self.jumlah()?;
+                if self.ambil() != Some(Token::Tutup) {
+                    return Err(pesan::teks("kalkulator.kurung_tutup").to_string());
+                }
+                // "(1 + 2) km" melampirkan satuan pada hasil tanpa dimensi di dalam kurung
+                if let Some(Token::Satuan(_)) = self.lihat() {
+                    if isi.dimensi == Dimensi::TANPA {
+                        return self.lampirkan_satuan(isi.nilai);
+                    }
+                }
+                Ok(isi)
+            }
+            // Satuan tanpa angka bernilai 1 satuan: "km / h"
+            Some(Token::Satuan(_)) => {
+                self.posisi -= 1;
+                self.lampirkan_satuan(1.0)
+            }
+            Some(token) => Err(pesan::format("kalkulator.token_tak_terduga", &[&token])),
+            None => Err(pesan::teks("kalkulator.berakhir_terlalu_cepat").to_string()),
+        }
+    }
+
+    fn lampirkan_satuan(&mut self, nilai: f64) -> Result<Besaran, String> {
+        match self.lihat().cloned() {
+            Some(Token::Satuan(nama)) => {
+                self.posisi += 1;
+                let satuan = self.registry.urai(&nama).map_err(|e| e.to_string())?;
+                for teks in saran::catatan_tafsiran(self.registry, &nama) {
+                    if !self.catatan.contains(&teks) {
+                        self.catatan.push(teks);
+                    }
+                }
+                let titik_nol = (satuan.offset() != 0.0).then(|| satuan.ke_dasar(0.0));
+                let besaran = Besaran { nilai: satuan.ke_dasar(nilai), dimensi: satuan.dimensi(), titik_nol };
+                self.dipakai.push(satuan);
+                Ok(besaran)
+            }
+            _ => Ok(Besaran::baru(nilai, Dimensi::TANPA)),
+        }
+    }
+}
+
+// Suhu pada skala bergeser ditolak: 50 °F + 10 °F bukan 60 °F bila dihitung lewat °C
+fn tambahkan(kiri: Besaran, kanan: Besaran, kurang: bool) -> Result<Besaran, String> {
+    let (kiri, kanan) = (kiri.tanpa_titik_nol()?, kanan.tanpa_titik_nol()?);
+    if kiri.dimensi != kanan.dimensi {
+        let kunci = if kurang { "kalkulator.tidak_dapat_kurang" } else { "kalkulator.tidak_dapat_jumlah" };
+        return Err(pesan::format(kunci, &[&kiri.dimensi, &kanan.dimensi]));
+    }
+    let nilai = if kurang { kiri.nilai - kanan.nilai } else { kiri.nilai + kanan.nilai };
+    Ok(Besaran::baru(nilai, kiri.dimensi))
+}
+
+// Pangkat dimensi di luar rentang i8 (misal (2 m)^100 * (2 m)^100) menjadi galat, bukan panic
+fn dimensi_hasil(dimensi: Option<Dimensi>) -> Result<Dimensi, String> {
+    dimensi.ok_or_else(|| pesan::teks("kalkulator.pangkat_terlalu_besar").to_string())
+}
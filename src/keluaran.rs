@@ -0,0 +1,503 @@
+use crate::awalan::JenisAwalan;
+use crate::converter::{self, KonversiError};
+#[cfg(feature = "big-decimal")]
+use crate::desimal;
+use crate::eksak::{self, OpsiEksak};
+use crate::history;
+use crate::kalkulator::HasilEvaluasi;
+use crate::lokal::Lokal;
+use crate::models::{CatatanKonversi, Kategori, Satuan, UnitRegistry};
+use crate::pembulatan::FormatAngka;
+use crate::pesan;
+use crate::saran;
+use num_traits::ToPrimitive;
+use serde::Serialize;
+
+// Format keluaran global (--output). Nama field JSON dan urutan kolom TSV di bawah ini adalah
+// kontrak untuk skrip: jangan diubah tanpa menaikkan versi mayor. TSV tidak memiliki header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum FormatKeluaran {
+    #[default]
+    Text,
+    Json,
+    Tsv,
+}
+
+// Format keluaran beserta aturan pembulatan angka
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tampilan {
+    pub keluaran: FormatKeluaran,
+    pub angka: FormatAngka,
+}
+
+/// Skema JSON `convert`. Semua field selalu ada; yang tidak berlaku bernilai `null`.
+/// TSV: `input<TAB>from_symbol<TAB>result<TAB>to_symbol` (dengan --exact, `result` berisi hasil persis).
+#[derive(Debug, Serialize)]
+pub struct KeluaranKonversi {
+    /// Nilai masukan, `null` jika bukan angka
+    pub input: Option<f64>,
+    /// Satuan asal seperti yang diketik pengguna
+    pub from: String,
+    /// Satuan tujuan seperti yang diketik pengguna
+    pub to: String,
+    /// Simbol satuan asal yang dikenali, misal "°F"
+    pub from_symbol: Option<String>,
+    /// Simbol satuan tujuan yang dikenali
+    pub to_symbol: Option<String>,
+    /// Hasil konversi, `null` jika gagal
+    pub result: Option<f64>,
+    /// Hasil persis dengan --exact ("127/5000" atau "2.54") atau desimal penuh dengan
+    /// --big-decimal; selain itu `null`
+    pub exact: Option<String>,
+    /// Nilai yang sama dalam satuan lain sekategori
+    pub alternatives: Vec<NilaiSatuan>,
+    /// Pesan kesalahan, `null` jika berhasil
+    pub error: Option<String>,
+    /// Catatan tafsiran ejaan ambigu, misal "gal" yang diartikan galon AS; kosong jika tidak ada
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NilaiSatuan {
+    pub value: f64,
+    pub unit: String,
+}
+
+/// Skema JSON `eval`. TSV: `expression<TAB>result<TAB>unit` (`unit` kosong untuk hasil tanpa satuan).
+#[derive(Debug, Serialize)]
+pub struct KeluaranEvaluasi {
+    /// Ekspresi tanpa satuan tujuan, seperti yang diketik pengguna
+    pub expression: String,
+    /// Hasil evaluasi, `null` jika gagal
+    pub result: Option<f64>,
+    /// Simbol satuan hasil, `null` untuk hasil tanpa satuan atau jika gagal
+    pub unit: Option<String>,
+    /// Pesan kesalahan, `null` jika berhasil
+    pub error: Option<String>,
+    /// Catatan tafsiran ejaan ambigu; kosong jika tidak ada
+    pub notes: Vec<String>,
+}
+
+/// Skema JSON `list`. TSV: `category<TAB>name<TAB>symbol<TAB>aliases` (alias dipisah koma,
+/// kategori "lainnya" untuk satuan tanpa kategori).
+#[derive(Debug, Serialize)]
+pub struct KeluaranDaftar {
+    pub categories: Vec<KategoriDaftar>,
+    /// Satuan tanpa kategori bawaan (misal newton)
+    pub uncategorized: Vec<SatuanDaftar>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct KategoriDaftar {
+    /// Nama kategori: "suhu", "panjang", ...
+    pub category: Kategori,
+    /// Dimensi, misal "L·T⁻¹"
+    pub dimension: String,
+    pub units: Vec<SatuanDaftar>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SatuanDaftar {
+    /// Nama kanonik dari tabel bawaan, sama untuk semua --lang
+    pub name: String,
+    /// Nama dalam bahasa --lang, untuk ditampilkan ke pengguna
+    pub display_name: String,
+    pub symbol: String,
+    pub aliases: Vec<String>,
+    pub dimension: String,
+    /// Faktor terhadap satuan SI koheren dimensinya
+    pub factor: f64,
+    pub offset: f64,
+    /// Awalan yang diterima: "tidak", "si", "biner", "data" atau "semua"
+    pub prefixes: JenisAwalan,
+}
+
+impl SatuanDaftar {
+    fn dari_satuan(satuan: &Satuan) -> SatuanDaftar {
+        SatuanDaftar {
+            name: satuan.nama_kanonik().to_string(),
+            display_name: satuan.nama().to_string(),
+            symbol: satuan.simbol().to_string(),
+            aliases: satuan.alias().to_vec(),
+            dimension: satuan.dimensi().to_string(),
+            factor: satuan.faktor(),
+            offset: satuan.offset(),
+            prefixes: satuan.awalan(),
+        }
+    }
+}
+
+// Konversi nilai ke satuan lain sekategori (selain asal dan tujuan)
+pub fn konversi_lainnya(nilai: f64, asal: &Satuan, tujuan: &Satuan) -> Vec<(f64, Satuan)> {
+    let sekategori = asal.kategori().map(|k| k.satuan_satuan()).unwrap_or_default();
+    let mut lainnya = Vec::new();
+    for satuan in sekategori {
+        if !satuan.setara(asal) && !satuan.setara(tujuan) {
+            if let Ok(val) = converter::konversi(nilai, asal, &satuan) {
+                lainnya.push((val, satuan));
+            }
+        }
+    }
+    lainnya
+}
+
+// "10 kg = 22.04 lbs (10000 g, 352.73 oz)"
+pub fn format_konversi(nilai: f64, asal: &Satuan, hasil: f64, tujuan: &Satuan, angka: FormatAngka) -> String {
+    let angka = angka.untuk_input(nilai);
+    let lainnya: Vec<String> = konversi_lainnya(nilai, asal, tujuan)
+        .iter()
+        .map(|(val, satuan)| format!("{} {}", angka.format(*val), satuan.simbol()))
+        .collect();
+
+    let string_lainnya = if lainnya.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", lainnya.join(", "))
+    };
+
+    format!("{} {} = {} {}{}", angka.lokal.format(nilai), asal.simbol(), angka.format(hasil), tujuan.simbol(), string_lainnya)
+}
+
+// JSON selalu berisi f64 penuh; pembulatan hanya untuk teks dan TSV
+pub fn cetak_konversi(tampilan: Tampilan, nilai: f64, dari: &str, ke: &str, asal: &Satuan, hasil: f64, tujuan: &Satuan) {
+    let angka = tampilan.angka.untuk_input(nilai);
+    let catatan = catatan_konversi(dari, ke);
+    if tampilan.keluaran != FormatKeluaran::Json {
+        cetak_catatan(&catatan);
+    }
+    match tampilan.keluaran {
+        FormatKeluaran::Text => println!("{}", format_konversi(nilai, asal, hasil, tujuan, angka)),
+        FormatKeluaran::Tsv => {
+            println!("{}\t{}\t{}\t{}", angka.lokal.format(nilai), asal.simbol(), angka.format(hasil), tujuan.simbol())
+        }
+        FormatKeluaran::Json => {
+            let alternatives = konversi_lainnya(nilai, asal, tujuan)
+                .into_iter()
+                .map(|(value, satuan)| NilaiSatuan { value, unit: satuan.simbol().to_string() })
+                .collect();
+            cetak_json(&KeluaranKonversi {
+                input: Some(nilai),
+                from: dari.to_string(),
+                to: ke.to_string(),
+                from_symbol: Some(asal.simbol().to_string()),
+                to_symbol: Some(tujuan.simbol().to_string()),
+                result: Some(hasil),
+                exact: None,
+                alternatives,
+                error: None,
+                notes: catatan,
+            });
+        }
+    }
+}
+
+// "120 km / 1.5 h = 80 km/h"; tanpa nilai masukan tunggal, --sig-figs auto menampilkan semua digit
+pub fn format_evaluasi(ekspresi: &str, hasil: &HasilEvaluasi, angka: FormatAngka) -> String {
+    match &hasil.satuan {
+        Some(satuan) => format!("{} = {} {}", ekspresi, angka.format(hasil.nilai), satuan.simbol()),
+        None => format!("{} = {}", ekspresi, angka.format(hasil.nilai)),
+    }
+}
+
+pub fn cetak_evaluasi(tampilan: Tampilan, ekspresi: &str, hasil: &HasilEvaluasi) {
+    if tampilan.keluaran != FormatKeluaran::Json {
+        cetak_catatan(&hasil.catatan);
+    }
+    let simbol = hasil.satuan.as_ref().map(|s| s.simbol().to_string());
+    match tampilan.keluaran {
+        FormatKeluaran::Text => println!("{}", format_evaluasi(ekspresi, hasil, tampilan.angka)),
+        FormatKeluaran::Tsv => {
+            println!("{}\t{}\t{}", ekspresi, tampilan.angka.format(hasil.nilai), simbol.unwrap_or_default())
+        }
+        FormatKeluaran::Json => cetak_json(&KeluaranEvaluasi {
+            expression: ekspresi.to_string(),
+            result: Some(hasil.nilai),
+            unit: simbol,
+            error: None,
+            notes: hasil.catatan.clone(),
+        }),
+    }
+}
+
+pub fn cetak_galat_evaluasi(format: FormatKeluaran, ekspresi: &str, pesan: &str) {
+    match format {
+        FormatKeluaran::Json => cetak_json(&KeluaranEvaluasi {
+            expression: ekspresi.to_string(),
+            result: None,
+            unit: None,
+            error: Some(pesan.to_string()),
+            notes: Vec::new(),
+        }),
+        FormatKeluaran::Text | FormatKeluaran::Tsv => eprintln!("{}", pesan::galat(pesan)),
+    }
+}
+
+// Mode --exact: hasil dan konversi lainnya dihitung sebagai pecahan persis
+// "1.234,5 kg = 123.450.000.000/45.359.237 lbs (1.234.500 g, ...)" dalam lokal yang dipilih
+pub fn format_konversi_eksak(
+    nilai: f64,
+    asal: &Satuan,
+    tujuan: &Satuan,
+    opsi: OpsiEksak,
+    lokal: Lokal,
+) -> Result<String, KonversiError> {
+    let nilai_eksak = eksak::dari_f64(nilai);
+    let hasil = eksak::konversi(&nilai_eksak, asal, tujuan)?;
+    let lainnya: Vec<String> = konversi_lainnya(nilai, asal, tujuan)
+        .iter()
+        .filter_map(|(_, satuan)| {
+            let val = eksak::konversi(&nilai_eksak, asal, satuan).ok()?;
+            Some(format!("{} {}", terapkan_eksak(lokal, &eksak::format(&val, opsi.digit)), satuan.simbol()))
+        })
+        .collect();
+
+    let string_lainnya = if lainnya.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", lainnya.join(", "))
+    };
+
+    let teks_hasil = terapkan_eksak(lokal, &eksak::format(&hasil, opsi.digit));
+    Ok(format!("{} {} = {} {}{}", lokal.format(nilai), asal.simbol(), teks_hasil, tujuan.simbol(), string_lainnya))
+}
+
+pub fn cetak_konversi_eksak(
+    tampilan: Tampilan,
+    nilai: f64,
+    dari: &str,
+    ke: &str,
+    asal: &Satuan,
+    tujuan: &Satuan,
+    opsi: OpsiEksak,
+) -> Result<(), KonversiError> {
+    let nilai_eksak = eksak::dari_f64(nilai);
+    let hasil = eksak::konversi(&nilai_eksak, asal, tujuan)?;
+    let teks_hasil = eksak::format(&hasil, opsi.digit);
+    let lokal = tampilan.angka.lokal;
+    let catatan = catatan_konversi(dari, ke);
+    if tampilan.keluaran != FormatKeluaran::Json {
+        cetak_catatan(&catatan);
+    }
+
+    match tampilan.keluaran {
+        FormatKeluaran::Text => println!("{}", format_konversi_eksak(nilai, asal, tujuan, opsi, lokal)?),
+        FormatKeluaran::Tsv => {
+            println!("{}\t{}\t{}\t{}", lokal.format(nilai), asal.simbol(), terapkan_eksak(lokal, &teks_hasil), tujuan.simbol())
+        }
+        FormatKeluaran::Json => {
+            let alternatives = konversi_lainnya(nilai, asal, tujuan)
+                .into_iter()
+                .map(|(value, satuan)| NilaiSatuan { value, unit: satuan.simbol().to_string() })
+                .collect();
+            cetak_json(&KeluaranKonversi {
+                input: Some(nilai),
+                from: dari.to_string(),
+                to: ke.to_string(),
+                from_symbol: Some(asal.simbol().to_string()),
+                to_symbol: Some(tujuan.simbol().to_string()),
+                result: hasil.to_f64(),
+                exact: Some(teks_hasil),
+                alternatives,
+                error: None,
+                notes: catatan,
+            });
+        }
+    }
+    Ok(())
+}
+
+// Hasil eksak ("2204.62262", "1000/3") dalam bentuk lokal; field "exact" JSON tetap kanonik
+fn terapkan_eksak(lokal: Lokal, teks: &str) -> String {
+    match teks.split_once('/') {
+        Some((pembilang, penyebut)) => format!("{}/{}", lokal.terapkan(pembilang), lokal.terapkan(penyebut)),
+        None => lokal.terapkan(teks),
+    }
+}
+
+// Mode --big-decimal: nilai dan hasil dalam desimal presisi sembarang
+#[cfg(feature = "big-decimal")]
+pub fn cetak_konversi_desimal(
+    format: FormatKeluaran,
+    nilai: &bigdecimal::BigDecimal,
+    dari: &str,
+    ke: &str,
+    asal: &Satuan,
+    hasil: &bigdecimal::BigDecimal,
+    tujuan: &Satuan,
+) {
+    let teks_nilai = desimal::format(nilai);
+    let teks_hasil = desimal::format(hasil);
+    let catatan = catatan_konversi(dari, ke);
+    if format != FormatKeluaran::Json {
+        cetak_catatan(&catatan);
+    }
+
+    match format {
+        FormatKeluaran::Text => {
+            let lainnya: Vec<String> = konversi_lainnya(nilai.to_f64().unwrap_or(f64::NAN), asal, tujuan)
+                .iter()
+                .filter_map(|(_, satuan)| {
+                    let val = desimal::konversi(nilai, asal, satuan).ok()?;
+                    Some(format!("{} {}", desimal::format(&val), satuan.simbol()))
+                })
+                .collect();
+            let string_lainnya = if lainnya.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", lainnya.join(", "))
+            };
+            println!("{} {} = {} {}{}", teks_nilai, asal.simbol(), teks_hasil, tujuan.simbol(), string_lainnya);
+        }
+        FormatKeluaran::Tsv => println!("{}\t{}\t{}\t{}", teks_nilai, asal.simbol(), teks_hasil, tujuan.simbol()),
+        FormatKeluaran::Json => cetak_json(&KeluaranKonversi {
+            input: nilai.to_f64(),
+            from: dari.to_string(),
+            to: ke.to_string(),
+            from_symbol: Some(asal.simbol().to_string()),
+            to_symbol: Some(tujuan.simbol().to_string()),
+            result: hasil.to_f64(),
+            exact: Some(teks_hasil),
+            alternatives: Vec::new(),
+            error: None,
+            notes: catatan,
+        }),
+    }
+}
+
+// Mode JSON menulis objek dengan field "error" ke stdout agar skrip tetap dapat mengurainya
+pub fn cetak_galat_konversi(format: FormatKeluaran, nilai: Option<f64>, dari: &str, ke: &str, pesan: &str) {
+    let catatan = catatan_konversi(dari, ke);
+    match format {
+        FormatKeluaran::Json => cetak_json(&KeluaranKonversi {
+            input: nilai,
+            from: dari.to_string(),
+            to: ke.to_string(),
+            from_symbol: Satuan::dari_str(dari).map(|s| s.simbol().to_string()),
+            to_symbol: Satuan::dari_str(ke).map(|s| s.simbol().to_string()),
+            result: None,
+            exact: None,
+            alternatives: Vec::new(),
+            error: Some(pesan.to_string()),
+            notes: catatan,
+        }),
+        FormatKeluaran::Text | FormatKeluaran::Tsv => {
+            cetak_catatan(&catatan);
+            eprintln!("{}", pesan::galat(pesan));
+        }
+    }
+}
+
+fn catatan_konversi(dari: &str, ke: &str) -> Vec<String> {
+    saran::catatan_konversi(UnitRegistry::global(), dari, ke)
+}
+
+// Catatan tafsiran ke stderr agar stdout tetap berisi hasil saja
+pub fn cetak_catatan(catatan: &[String]) {
+    for teks in catatan {
+        eprintln!("{}", pesan::format("umum.catatan", &[teks]));
+    }
+}
+
+pub fn cetak_daftar(format: FormatKeluaran) {
+    let registry = UnitRegistry::global();
+    match format {
+        FormatKeluaran::Text => tampilkan_daftar(),
+        FormatKeluaran::Json => {
+            let categories = Kategori::semua()
+                .into_iter()
+                .map(|kategori| KategoriDaftar {
+                    category: kategori,
+                    dimension: kategori.dimensi().to_string(),
+                    units: kategori.satuan_satuan().iter().map(SatuanDaftar::dari_satuan).collect(),
+                })
+                .collect();
+            let uncategorized = registry
+                .semua()
+                .iter()
+                .filter(|s| s.kategori().is_none())
+                .map(SatuanDaftar::dari_satuan)
+                .collect();
+            cetak_json(&KeluaranDaftar { categories, uncategorized });
+        }
+        FormatKeluaran::Tsv => {
+            for satuan in registry.semua() {
+                let kategori = satuan.kategori().map(|k| k.nama()).unwrap_or("lainnya");
+                println!("{}\t{}\t{}\t{}", kategori, satuan.nama_kanonik(), satuan.simbol(), satuan.alias().join(","));
+            }
+        }
+    }
+}
+
+/// JSON `history` adalah larik `CatatanKonversi` apa adanya (field `satuan_asal`, `satuan_tujuan`,
+/// `nilai_input`, serta `nilai_output` atau `pesan_error`). TSV:
+/// `nilai_input<TAB>satuan_asal<TAB>nilai_output<TAB>satuan_tujuan<TAB>pesan_error`.
+pub fn cetak_riwayat(tampilan: Tampilan) -> Result<(), String> {
+    let format = tampilan.keluaran;
+    let daftar_catatan: Vec<CatatanKonversi> = match format {
+        FormatKeluaran::Text => {
+            history::tampilkan_riwayat(tampilan.angka);
+            return Ok(());
+        }
+        _ => history::baca_riwayat()?,
+    };
+
+    if format == FormatKeluaran::Json {
+        cetak_json(&daftar_catatan);
+        return Ok(());
+    }
+    for catatan in &daftar_catatan {
+        println!(
+            "{}\t{}\t{}\t{}\t{}",
+            catatan.nilai_input,
+            catatan.satuan_asal,
+            catatan
+                .nilai_output
+                .map(|n| tampilan.angka.untuk_input(catatan.nilai_input).format(n))
+                .unwrap_or_default(),
+            catatan.satuan_tujuan,
+            catatan.pesan_error.as_deref().unwrap_or("")
+        );
+    }
+    Ok(())
+}
+
+fn cetak_json<T: Serialize>(data: &T) {
+    match serde_json::to_string_pretty(data) {
+        Ok(teks) => println!("{}", teks),
+        Err(e) => eprintln!("{}", pesan::galat(pesan::format("umum.gagal_json", &[&e]))),
+    }
+}
+
+pub fn tampilkan_daftar() {
+    let registry = UnitRegistry::global();
+
+    println!("{}", pesan::teks("daftar.judul"));
+    for (i, kategori) in Kategori::semua().into_iter().enumerate() {
+        let nama: Vec<String> = kategori
+            .satuan_satuan()
+            .iter()
+            .map(|s| s.nama().to_string())
+            .collect();
+        println!("{}. [{}] {}", i + 1, kategori.nama_tampilan(), nama.join(", "));
+    }
+
+    let tanpa_kategori: Vec<String> = registry
+        .semua()
+        .iter()
+        .filter(|s| s.kategori().is_none())
+        .map(|s| format!("{} [{}]", s.nama(), s.dimensi()))
+        .collect();
+    if !tanpa_kategori.is_empty() {
+        let lainnya = pesan::teks("daftar.lainnya");
+        println!("{}. [{}] {}", Kategori::semua().len() + 1, lainnya, tanpa_kategori.join(", "));
+    }
+
+    let berawalan: Vec<String> = registry
+        .semua()
+        .iter()
+        .filter(|s| s.awalan() != JenisAwalan::Tidak)
+        .map(|s| s.simbol().to_string())
+        .collect();
+    println!("{}", pesan::format("daftar.awalan", &[&berawalan.join(", ")]));
+    println!("{}", pesan::teks("daftar.ekspresi"));
+}
@@ -0,0 +1,216 @@
+use crate::converter::{self, KonversiError};
+use crate::lokal::Lokal;
+use crate::models::{Satuan, UnitRegistry};
+use crate::pesan;
+use crate::pembulatan::FormatAngka;
+use crate::saran::{self, urai_dengan_saran};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+// Asal satuan nilai di kolom: tetap untuk semua baris, atau dibaca dari kolom lain per baris
+pub enum SumberSatuan {
+    Tetap(String),
+    Kolom(String),
+}
+
+// SumberSatuan setelah nama kolom diterjemahkan ke indeks
+enum KolomSatuan<'a> {
+    Tetap(&'a str),
+    Kolom(usize),
+}
+
+pub struct OpsiCsv {
+    pub input: Option<PathBuf>,
+    pub keluaran: Option<PathBuf>,
+    pub kolom: String,
+    pub sumber: SumberSatuan,
+    pub tujuan: String,
+    // None: ganti kolom asal; Some: tambahkan kolom baru dengan nama ini (kosong = otomatis)
+    pub kolom_baru: Option<String>,
+    pub tanpa_header: bool,
+    pub pemisah: u8,
+    pub angka: FormatAngka,
+}
+
+pub struct BarisGagal {
+    pub baris: usize,
+    pub pesan: String,
+}
+
+pub struct RingkasanCsv {
+    pub berhasil: usize,
+    pub gagal: Vec<BarisGagal>,
+    // Catatan tafsiran ejaan ambigu, satu per ejaan, untuk dicetak pemanggil
+    pub catatan: Vec<String>,
+}
+
+// Mengonversi satu kolom CSV. Sel kosong dibiarkan apa adanya; sel yang gagal tetap berisi nilai
+// aslinya (mode ganti) atau kosong (mode tambah) dan dicatat di ringkasan beserta nomor barisnya.
+pub fn jalankan(opsi: &OpsiCsv) -> Result<RingkasanCsv, String> {
+    let sumber: Box<dyn Read> = match &opsi.input {
+        Some(path) if path.as_os_str() != "-" => Box::new(
+            File::open(path).map_err(|e| pesan::format("umum.gagal_buka_berkas", &[&path.display(), &e]))?,
+        ),
+        _ => Box::new(io::stdin().lock()),
+    };
+    let tujuan: Box<dyn Write> = match &opsi.keluaran {
+        Some(path) => Box::new(
+            File::create(path).map_err(|e| pesan::format("umum.gagal_buat_berkas", &[&path.display(), &e]))?,
+        ),
+        None => Box::new(io::stdout().lock()),
+    };
+
+    let mut pembaca = csv::ReaderBuilder::new()
+        .delimiter(opsi.pemisah)
+        .has_headers(!opsi.tanpa_header)
+        .flexible(true)
+        .from_reader(sumber);
+    let mut penulis = csv::WriterBuilder::new()
+        .delimiter(opsi.pemisah)
+        .flexible(true)
+        .from_writer(tujuan);
+
+    let header = if opsi.tanpa_header {
+        None
+    } else {
+        Some(pembaca.headers().map_err(|e| pesan::format("csv.gagal_baca_header", &[&e]))?.clone())
+    };
+
+    let indeks_nilai = pilih_kolom(header.as_ref(), &opsi.kolom)?;
+    let kolom_satuan = match &opsi.sumber {
+        SumberSatuan::Kolom(kolom) => KolomSatuan::Kolom(pilih_kolom(header.as_ref(), kolom)?),
+        SumberSatuan::Tetap(dari) => KolomSatuan::Tetap(dari),
+    };
+
+    let registry = UnitRegistry::global();
+    let mut ringkasan = RingkasanCsv { berhasil: 0, gagal: Vec::new(), catatan: Vec::new() };
+    let satuan_tujuan = urai_dengan_saran("tujuan", &opsi.tujuan).map_err(|e| e.to_string())?;
+    ringkasan.catatan.extend(saran::catatan_tafsiran(registry, &opsi.tujuan));
+    let mut satuan_asal: HashMap<String, Result<Satuan, KonversiError>> = HashMap::new();
+    if let SumberSatuan::Tetap(dari) = &opsi.sumber {
+        // Satuan tetap yang salah adalah kesalahan fatal, bukan kegagalan per baris
+        let satuan = urai_dengan_saran("asal", dari).map_err(|e| e.to_string())?;
+        tambah_catatan(&mut ringkasan.catatan, saran::catatan_tafsiran(registry, dari));
+        satuan_asal.insert(dari.clone(), Ok(satuan));
+    }
+
+    // Mode ganti mempertahankan header apa adanya; mode tambah memberi nama kolom baru
+    if let Some(header) = &header {
+        let mut header_baru = header.clone();
+        if let Some(nama) = &opsi.kolom_baru {
+            let nama = if nama.is_empty() {
+                format!("{}_{}", &header[indeks_nilai], satuan_tujuan.simbol())
+            } else {
+                nama.clone()
+            };
+            header_baru.push_field(&nama);
+        }
+        penulis.write_record(&header_baru).map_err(|e| pesan::format("csv.gagal_tulis", &[&e]))?;
+    }
+
+    for (i, rekaman) in pembaca.records().enumerate() {
+        let rekaman = rekaman.map_err(|e| pesan::format("csv.gagal_baca", &[&e]))?;
+        // Nomor baris di berkas, termasuk header
+        let baris = rekaman.position().map(|p| p.line() as usize).unwrap_or(i + 1 + header.is_some() as usize);
+
+        let jumlah_satuan = satuan_asal.len();
+        let hasil = konversi_sel(&rekaman, indeks_nilai, &kolom_satuan, &mut satuan_asal, &satuan_tujuan, opsi.angka.lokal);
+        // Satuan yang baru masuk cache belum pernah diperiksa tafsirannya
+        if satuan_asal.len() > jumlah_satuan {
+            if let KolomSatuan::Kolom(indeks) = kolom_satuan {
+                let nama = rekaman.get(indeks).map(str::trim).unwrap_or("");
+                tambah_catatan(&mut ringkasan.catatan, saran::catatan_tafsiran(registry, nama));
+            }
+        }
+        let nilai_baru = match hasil {
+            Ok(Some((_, hasil))) => {
+                ringkasan.berhasil += 1;
+                let teks_nilai = rekaman.get(indeks_nilai).map(str::trim).unwrap_or_default();
+                opsi.angka.untuk_teks(teks_nilai).format(hasil)
+            }
+            Ok(None) => String::new(),
+            Err(pesan) => {
+                ringkasan.gagal.push(BarisGagal { baris, pesan });
+                match opsi.kolom_baru {
+                    Some(_) => String::new(),
+                    None => rekaman.get(indeks_nilai).unwrap_or("").to_string(),
+                }
+            }
+        };
+
+        let mut kolom: Vec<&str> = rekaman.iter().collect();
+        match opsi.kolom_baru {
+            Some(_) => kolom.push(&nilai_baru),
+            None if indeks_nilai < kolom.len() => kolom[indeks_nilai] = &nilai_baru,
+            None => {}
+        }
+        penulis.write_record(&kolom).map_err(|e| pesan::format("csv.gagal_tulis", &[&e]))?;
+    }
+    penulis.flush().map_err(|e| pesan::format("csv.gagal_tulis", &[&e]))?;
+
+    Ok(ringkasan)
+}
+
+fn tambah_catatan(catatan: &mut Vec<String>, baru: Vec<String>) {
+    for teks in baru {
+        if !catatan.contains(&teks) {
+            catatan.push(teks);
+        }
+    }
+}
+
+// Nama kolom dari header, atau indeks mulai dari 1
+fn pilih_kolom(header: Option<&csv::StringRecord>, pemilih: &str) -> Result<usize, String> {
+    if let Some(posisi) = header.and_then(|h| h.iter().position(|nama| nama.trim() == pemilih.trim())) {
+        return Ok(posisi);
+    }
+    let tidak_ditemukan = |header: &csv::StringRecord| {
+        pesan::format("csv.kolom_tidak_ditemukan", &[&pemilih, &header.iter().collect::<Vec<_>>().join(", ")])
+    };
+    match (pemilih.trim().parse::<usize>(), header) {
+        (Ok(0), _) => Err(pesan::teks("csv.indeks_mulai_satu").to_string()),
+        // Tanpa header, baris yang kekurangan kolom dilaporkan satu per satu saat dikonversi
+        (Ok(n), Some(header)) if n > header.len() => Err(tidak_ditemukan(header)),
+        (Ok(n), _) => Ok(n - 1),
+        (Err(_), Some(header)) => Err(tidak_ditemukan(header)),
+        (Err(_), None) => Err(pesan::format("csv.kolom_harus_indeks", &[&pemilih])),
+    }
+}
+
+fn konversi_sel(
+    rekaman: &csv::StringRecord,
+    indeks_nilai: usize,
+    kolom_satuan: &KolomSatuan,
+    cache: &mut HashMap<String, Result<Satuan, KonversiError>>,
+    tujuan: &Satuan,
+    lokal: Lokal,
+) -> Result<Option<(f64, f64)>, String> {
+    let teks = rekaman
+        .get(indeks_nilai)
+        .ok_or_else(|| pesan::format("csv.kolom_kurang", &[&rekaman.len()]))?
+        .trim();
+    if teks.is_empty() {
+        return Ok(None);
+    }
+    let nilai = lokal.urai_nilai(teks).map_err(|e| e.to_string())?;
+
+    let nama_satuan = match *kolom_satuan {
+        KolomSatuan::Tetap(dari) => dari,
+        KolomSatuan::Kolom(indeks) => rekaman
+            .get(indeks)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| pesan::teks("csv.satuan_kosong"))?,
+    };
+    let asal = cache
+        .entry(nama_satuan.to_string())
+        .or_insert_with(|| urai_dengan_saran("asal", nama_satuan))
+        .clone()
+        .map_err(|e| e.to_string())?;
+
+    converter::konversi(nilai, &asal, tujuan)
+        .map(|hasil| Some((nilai, hasil)))
+        .map_err(|e| e.to_string())
+}
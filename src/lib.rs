@@ -1,4 +1,22 @@
+pub mod awalan;
+pub mod batch;
+pub mod besaran;
 pub mod models;
+pub mod pembulatan;
+pub mod repl;
 pub mod converter;
+pub mod definisi;
+#[cfg(feature = "big-decimal")]
+pub mod desimal;
+pub mod dimensi;
+pub mod eksak;
+pub mod ekspresi;
 pub mod history;
+pub mod kalkulator;
+pub mod lokal;
+pub mod konversi_csv;
+pub mod keluaran;
+pub mod pelengkap;
+pub mod pesan;
+pub mod saran;
 pub mod cli;
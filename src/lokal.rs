@@ -0,0 +1,193 @@
+// Format angka per lokal: pemisah desimal, pemisah ribuan dan ambang notasi ilmiah.
+// Lokal bawaan mempertahankan perilaku lama (titik desimal, tanpa pemisah ribuan).
+use crate::converter::KonversiError;
+use crate::pesan;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lokal {
+    kode: &'static str,
+    desimal: char,
+    ribuan: Option<char>,
+    minus: char,
+}
+
+// Di luar rentang ini nilai penuh ditulis dalam notasi ilmiah (kecuali lokal bawaan)
+const AMBANG_BESAR: f64 = 1e15;
+const AMBANG_KECIL: f64 = 1e-6;
+
+impl Lokal {
+    pub const BAWAAN: Lokal = Lokal { kode: "C", desimal: '.', ribuan: None, minus: '-' };
+    pub const ID_ID: Lokal = Lokal { kode: "id-ID", desimal: ',', ribuan: Some('.'), minus: '-' };
+    pub const EN_US: Lokal = Lokal { kode: "en-US", desimal: '.', ribuan: Some(','), minus: '-' };
+    pub const EN_GB: Lokal = Lokal { kode: "en-GB", desimal: '.', ribuan: Some(','), minus: '-' };
+    pub const DE_DE: Lokal = Lokal { kode: "de-DE", desimal: ',', ribuan: Some('.'), minus: '-' };
+    // Spasi sempit tak terputus seperti pada CLDR
+    pub const FR_FR: Lokal = Lokal { kode: "fr-FR", desimal: ',', ribuan: Some('\u{202f}'), minus: '-' };
+    // Tanda minus matematis U+2212
+    pub const SV_SE: Lokal = Lokal { kode: "sv-SE", desimal: ',', ribuan: Some('\u{a0}'), minus: '−' };
+
+    const SEMUA: [Lokal; 7] = [
+        Lokal::BAWAAN,
+        Lokal::ID_ID,
+        Lokal::EN_US,
+        Lokal::EN_GB,
+        Lokal::DE_DE,
+        Lokal::FR_FR,
+        Lokal::SV_SE,
+    ];
+
+    pub fn kode(&self) -> &'static str {
+        self.kode
+    }
+
+    // Dari variabel lingkungan POSIX (LC_ALL, LC_NUMERIC, LANG), misal "id_ID.UTF-8"
+    pub fn dari_env() -> Lokal {
+        ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .iter()
+            .filter_map(|nama| std::env::var(nama).ok())
+            .find(|nilai| !nilai.is_empty())
+            .and_then(|nilai| Lokal::dari_kode(&nilai))
+            .unwrap_or(Lokal::BAWAAN)
+    }
+
+    // "id-ID", "id_ID.UTF-8", "id", "en"; bahasa tanpa wilayah memakai wilayah utamanya
+    fn dari_kode(kode: &str) -> Option<Lokal> {
+        let kode = kode.split(['.', '@']).next().unwrap_or(kode).replace('_', "-");
+        if kode.eq_ignore_ascii_case("C") || kode.eq_ignore_ascii_case("POSIX") {
+            return Some(Lokal::BAWAAN);
+        }
+        if let Some(lokal) = Lokal::SEMUA.iter().find(|l| l.kode.eq_ignore_ascii_case(&kode)) {
+            return Some(*lokal);
+        }
+
+        let bahasa = kode.split('-').next().unwrap_or("").to_ascii_lowercase();
+        match bahasa.as_str() {
+            "id" | "ms" => Some(Lokal::ID_ID),
+            "en" => Some(Lokal::EN_US),
+            "de" | "nl" | "es" | "it" | "pt" | "da" => Some(Lokal::DE_DE),
+            "fr" => Some(Lokal::FR_FR),
+            "sv" | "fi" | "nb" => Some(Lokal::SV_SE),
+            _ => None,
+        }
+    }
+
+    // Teks angka lokal ke bentuk kanonik: "1.234,5" (id-ID) atau "1,234.5" (en-US) -> "1234.5".
+    // Tanda minus Unicode (−) diterima di semua lokal. None bila pemisahnya salah tempat,
+    // misal "1,234.5" pada id-ID, agar tidak terbaca diam-diam sebagai angka lain.
+    pub fn normalkan(&self, teks: &str) -> Option<String> {
+        let normal = teks.trim().replace('−', "-");
+        let Some(ribuan) = self.ribuan.filter(|_| *self != Lokal::BAWAAN) else {
+            return Some(normal);
+        };
+        // Spasi biasa juga lazim dipakai sebagai pemisah ribuan di lokal berspasi
+        let normal: String = if ribuan.is_whitespace() {
+            normal.chars().map(|c| if c.is_whitespace() { ribuan } else { c }).collect()
+        } else {
+            normal
+        };
+
+        let (bulat, pecahan) = match normal.split_once(self.desimal) {
+            Some((bulat, pecahan)) => (bulat, Some(pecahan)),
+            None => (normal.as_str(), None),
+        };
+        if pecahan.is_some_and(|p| p.contains([self.desimal, ribuan])) {
+            return None;
+        }
+        // Kelompok ribuan: 1-3 digit di depan, lalu tepat 3 digit
+        let kelompok: Vec<&str> = bulat.split(ribuan).collect();
+        let digit_depan = kelompok[0].trim_start_matches(['-', '+']).len();
+        if kelompok.len() > 1 && (!(1..=3).contains(&digit_depan) || kelompok[1..].iter().any(|k| k.len() != 3)) {
+            return None;
+        }
+
+        let mut hasil = kelompok.concat();
+        if let Some(pecahan) = pecahan {
+            hasil.push('.');
+            hasil.push_str(pecahan);
+        }
+        Some(hasil)
+    }
+
+    pub fn urai_nilai(&self, teks: &str) -> Result<f64, KonversiError> {
+        let asli = teks.trim();
+        let nilai: f64 = self
+            .normalkan(asli)
+            .and_then(|normal| normal.parse().ok())
+            .ok_or_else(|| KonversiError::NilaiTidakValid(asli.to_string()))?;
+        if !nilai.is_finite() {
+            return Err(KonversiError::NilaiTidakHingga);
+        }
+        Ok(nilai)
+    }
+
+    // Nilai penuh (tanpa pembulatan); notasi ilmiah untuk nilai sangat besar atau sangat kecil
+    pub fn format(&self, nilai: f64) -> String {
+        if *self == Lokal::BAWAAN || !nilai.is_finite() {
+            return nilai.to_string();
+        }
+        let mutlak = nilai.abs();
+        if mutlak >= AMBANG_BESAR || (mutlak > 0.0 && mutlak < AMBANG_KECIL) {
+            let teks = format!("{:e}", nilai);
+            return teks.replace('.', &self.desimal.to_string()).replace('-', &self.minus.to_string());
+        }
+        self.terapkan(&nilai.to_string())
+    }
+
+    // Teks desimal kanonik ("-1234.5") ke bentuk lokal ("-1.234,5")
+    pub fn terapkan(&self, teks: &str) -> String {
+        if *self == Lokal::BAWAAN {
+            return teks.to_string();
+        }
+        let (tanda, angka) = match teks.strip_prefix('-') {
+            Some(sisa) => (Some(self.minus), sisa),
+            None => (None, teks),
+        };
+        let (bulat, pecahan) = match angka.split_once('.') {
+            Some((bulat, pecahan)) => (bulat, Some(pecahan)),
+            None => (angka, None),
+        };
+
+        let mut hasil: String = tanda.into_iter().collect();
+        for (i, c) in bulat.chars().enumerate() {
+            if i > 0 && (bulat.len() - i) % 3 == 0 {
+                if let Some(ribuan) = self.ribuan {
+                    hasil.push(ribuan);
+                }
+            }
+            hasil.push(c);
+        }
+        if let Some(pecahan) = pecahan {
+            hasil.push(self.desimal);
+            hasil.push_str(pecahan);
+        }
+        hasil
+    }
+}
+
+impl Default for Lokal {
+    fn default() -> Lokal {
+        Lokal::BAWAAN
+    }
+}
+
+impl FromStr for Lokal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Lokal, String> {
+        if s.eq_ignore_ascii_case("auto") {
+            return Ok(Lokal::dari_env());
+        }
+        Lokal::dari_kode(s).ok_or_else(|| {
+            let tersedia: Vec<&str> = Lokal::SEMUA.iter().map(|l| l.kode).collect();
+            pesan::format("opsi.lokal_tidak_didukung", &[&s, &tersedia.join(", ")])
+        })
+    }
+}
+
+impl fmt::Display for Lokal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kode)
+    }
+}
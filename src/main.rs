@@ -1,146 +1,242 @@
-use clap::Parser;
+use minirustcli::batch;
 use minirustcli::cli::{Cli, Commands};
 use minirustcli::converter;
+use minirustcli::definisi;
+#[cfg(feature = "big-decimal")]
+use minirustcli::desimal;
+use minirustcli::eksak::OpsiEksak;
 use minirustcli::history;
-use minirustcli::models::Satuan;
+use minirustcli::kalkulator;
+use minirustcli::keluaran::{self, FormatKeluaran, Tampilan};
+use minirustcli::konversi_csv::{self, OpsiCsv, SumberSatuan};
+use minirustcli::models::UnitRegistry;
+use minirustcli::pelengkap;
+use minirustcli::pesan;
+use minirustcli::repl;
 use std::process;
-use std::cmp::min;
 
-// Implementasi sederhana jarak Levenshtein
-fn hitung_jarak_levenshtein(s1: &str, s2: &str) -> usize {
-    let panjang1 = s1.chars().count();
-    let panjang2 = s2.chars().count();
-    let mut matriks = vec![vec![0; panjang2 + 1]; panjang1 + 1];
-
-    for i in 0..=panjang1 { matriks[i][0] = i; }
-    for j in 0..=panjang2 { matriks[0][j] = j; }
+fn main() {
+    let cli = Cli::urai();
 
-    for (i, c1) in s1.chars().enumerate() {
-        for (j, c2) in s2.chars().enumerate() {
-            let biaya = if c1 == c2 { 0 } else { 1 };
-            matriks[i + 1][j + 1] = min(
-                min(matriks[i][j + 1] + 1, matriks[i + 1][j] + 1),
-                matriks[i][j] + biaya
-            );
+    let registry = definisi::siapkan_registry(cli.units_file.as_deref()).and_then(|mut registry| {
+        if let Some(atmosfer) = &cli.atmosphere {
+            definisi::atur_atmosfer(&mut registry, atmosfer)?;
         }
-    }
-    matriks[panjang1][panjang2]
-}
-
-fn saran_satuan(input: &str) -> Option<String> {
-    let daftar_satuan = Satuan::semua();
-    let mut cocok_terbaik = None;
-    let mut jarak_min = usize::MAX;
-
-    for satuan in daftar_satuan {
-        let nama = satuan.nama();
-        let jarak = hitung_jarak_levenshtein(input.to_lowercase().as_str(), nama);
-        if jarak < jarak_min {
-            jarak_min = jarak;
-            cocok_terbaik = Some(nama);
-        }
-        // Periksa (misal "kg", "cm")
-        let simbol = satuan.simbol().replace("°", ""); // Hapus simbol derajat
-        let jarak_simbol = hitung_jarak_levenshtein(input.to_lowercase().as_str(), &simbol.to_lowercase());
-        if jarak_simbol < jarak_min {
-            jarak_min = jarak_simbol;
-            cocok_terbaik = Some(satuan.nama());
+        if let Some(sistem) = cli.system {
+            registry.atur_sistem(sistem);
+        }
+        Ok(registry)
+    });
+    match registry {
+        Ok(registry) => {
+            let _ = UnitRegistry::pasang_global(registry);
+        }
+        Err(pesan) => {
+            eprintln!("{}", pesan::galat(pesan));
+            process::exit(1);
         }
     }
 
-    // Sarankan jika jarak kecil relatif terhadap panjang string
-    if jarak_min <= 3 {
-        cocok_terbaik.map(|s| s.to_string())
-    } else {
-        None
-    }
-}
+    let tampilan = Tampilan { keluaran: cli.output, angka: cli.format_angka() };
+    let opsi = OpsiKonversi {
+        tampilan,
+        eksak: cli.exact.then_some(OpsiEksak { digit: cli.digits }),
+        desimal_besar: cli.big_decimal,
+    };
 
-fn main() {
-    let cli = Cli::parse();
+    let command = match cli.command {
+        Some(command) => command,
+        None => {
+            // Bentuk singkat: unitconv 10kg lbs
+            let kueri = cli.kueri.join(" ");
+            match kalkulator::pisahkan_kueri(&kueri) {
+                Some((nilai, dari, ke)) => jalankan_konversi(nilai, dari, ke, &opsi),
+                None => {
+                    let pesan = pesan::format("umum.kueri_tidak_dipahami", &[&kueri]);
+                    keluaran::cetak_galat_konversi(cli.output, None, &kueri, "", &pesan);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+    };
 
-    match cli.command {
+    match command {
         Commands::Convert { from, to, value } => {
-            // Mapping argumen
-            let dari = from;
-            let ke = to;
-            let nilai_str = value;
+            jalankan_konversi(&value, &from, &to, &opsi);
+        }
+        Commands::List => {
+            keluaran::cetak_daftar(cli.output);
+        }
+        Commands::History => {
+            if let Err(pesan) = keluaran::cetak_riwayat(tampilan) {
+                eprintln!("{}", pesan::galat(pesan));
+                process::exit(1);
+            }
+        }
+        Commands::Eval { ekspresi, to } => {
+            let teks = ekspresi.join(" ");
+            let (ekspresi, tujuan) = match to.as_deref() {
+                Some(tujuan) => (teks.trim(), Some(tujuan)),
+                None => kalkulator::pisahkan_tujuan(&teks),
+            };
 
-            // Validasi input numerik manual untuk pesan error
-            let nilai: f64 = match nilai_str.parse() {
-                Ok(n) => n,
-                Err(_) => {
-                    let pesan = format!("Nilai '{}' bukan angka yang valid. Harap masukkan angka (contoh: 10, 1.5, -5).", nilai_str);
-                    eprintln!("Error: [KESALAHAN] {}", pesan);
+            match kalkulator::evaluasi(UnitRegistry::global(), ekspresi, tujuan) {
+                Ok(hasil) => keluaran::cetak_evaluasi(tampilan, ekspresi, &hasil),
+                Err(pesan) => {
+                    keluaran::cetak_galat_evaluasi(cli.output, ekspresi, &pesan);
                     process::exit(1);
                 }
-            };
-
-            let opsi_satuan_asal = Satuan::dari_str(&dari);
-            if opsi_satuan_asal.is_none() {
-                let mut pesan = format!("Satuan asal '{}' tidak dikenali.", dari);
-                if let Some(saran) = saran_satuan(&dari) {
-                    pesan.push_str(&format!(" Apakah maksud Anda '{}'?", saran));
+            }
+        }
+        Commands::Batch { input, to, no_history } => {
+            match batch::jalankan(input.as_deref(), to.as_deref(), !no_history, tampilan.angka) {
+                Ok(ringkasan) if ringkasan.gagal > 0 => {
+                    let total = ringkasan.gagal + ringkasan.berhasil;
+                    eprintln!("{}", pesan::format("batch.ringkasan", &[&ringkasan.gagal, &total]));
+                    process::exit(1);
+                }
+                Ok(_) => {}
+                Err(pesan) => {
+                    eprintln!("{}", pesan::galat(pesan));
+                    process::exit(1);
                 }
-                eprintln!("Error: [KESALAHAN] {}", pesan);
-                history::simpan_riwayat(&dari, &ke, nilai, None, Some(pesan));
+            }
+        }
+        Commands::Csv { input, column, from, unit_column, to, append, no_header, delimiter, out } => {
+            if !delimiter.is_ascii() {
+                eprintln!("{}", pesan::galat(pesan::teks("csv.pemisah_bukan_ascii")));
                 process::exit(1);
             }
-            let satuan_asal = opsi_satuan_asal.unwrap();
+            let opsi = OpsiCsv {
+                input,
+                keluaran: out,
+                kolom: column,
+                sumber: match (from, unit_column) {
+                    (_, Some(kolom)) => SumberSatuan::Kolom(kolom),
+                    (Some(dari), None) => SumberSatuan::Tetap(dari),
+                    (None, None) => unreachable!("clap mewajibkan --from atau --unit-column"),
+                },
+                tujuan: to,
+                kolom_baru: append,
+                tanpa_header: no_header,
+                pemisah: delimiter as u8,
+                angka: tampilan.angka,
+            };
 
-            let opsi_satuan_tujuan = Satuan::dari_str(&ke);
-            if opsi_satuan_tujuan.is_none() {
-                let mut pesan = format!("Satuan tujuan '{}' tidak dikenali.", ke);
-                if let Some(saran) = saran_satuan(&ke) {
-                    pesan.push_str(&format!(" Apakah maksud Anda '{}'?", saran));
+            let hasil = konversi_csv::jalankan(&opsi);
+            if let Ok(ringkasan) = &hasil {
+                keluaran::cetak_catatan(&ringkasan.catatan);
+            }
+            match hasil {
+                Ok(ringkasan) if !ringkasan.gagal.is_empty() => {
+                    let total = ringkasan.berhasil + ringkasan.gagal.len();
+                    eprintln!("{}", pesan::format("csv.ringkasan", &[&ringkasan.gagal.len(), &total]));
+                    for gagal in &ringkasan.gagal {
+                        eprintln!("{}", pesan::format("csv.baris_gagal", &[&gagal.baris, &gagal.pesan]));
+                    }
+                    process::exit(1);
                 }
-                eprintln!("Error: [KESALAHAN] {}", pesan);
-                history::simpan_riwayat(&dari, &ke, nilai, None, Some(pesan));
+                Ok(_) => {}
+                Err(pesan) => {
+                    eprintln!("{}", pesan::galat(pesan));
+                    process::exit(1);
+                }
+            }
+        }
+        Commands::Repl => {
+            if let Err(pesan) = repl::jalankan(tampilan.angka) {
+                eprintln!("{}", pesan::galat(pesan));
+                process::exit(1);
+            }
+        }
+        Commands::Completions { shell } => {
+            if let Err(galat) = pelengkap::cetak_skrip(shell, Cli::perintah(), &mut std::io::stdout()) {
+                eprintln!("{}", pesan::galat(galat));
                 process::exit(1);
             }
-            let satuan_tujuan = opsi_satuan_tujuan.unwrap();
+        }
+        Commands::CompleteUnits { from, awalan } => {
+            for ejaan in pelengkap::daftar_satuan(from.as_deref(), &awalan) {
+                println!("{}", ejaan);
+            }
+        }
+    }
+}
 
-            match converter::konversi(nilai, satuan_asal, satuan_tujuan) {
-                Ok(hasil) => {
-                    // Konversi satuan asal ke satuan tujuan
-                    let mut lainnya = Vec::new();
-                    for satuan in satuan_asal.kategori().satuan_satuan() {
-                        if satuan != satuan_asal && satuan != satuan_tujuan {
-                            if let Ok(val) = converter::konversi(nilai, satuan_asal, satuan) {
-                                lainnya.push(format!("{} {}", val, satuan.simbol()));
-                            }
-                        }
-                    }
+// Opsi global yang memengaruhi perintah konversi
+struct OpsiKonversi {
+    tampilan: Tampilan,
+    eksak: Option<OpsiEksak>,
+    desimal_besar: bool,
+}
 
-                    // Format output
-                    let string_lainnya = if lainnya.is_empty() {
-                        String::new()
-                    } else {
-                        format!(" ({})", lainnya.join(", "))
-                    };
+fn jalankan_konversi(nilai_teks: &str, dari: &str, ke: &str, opsi: &OpsiKonversi) {
+    let format = opsi.tampilan.keluaran;
+    if opsi.desimal_besar {
+        // Teks yang tidak dapat dinormalkan tetap diteruskan agar galatnya dilaporkan backend
+        let nilai_teks = opsi.tampilan.angka.lokal.normalkan(nilai_teks).unwrap_or_else(|| nilai_teks.to_string());
+        jalankan_konversi_desimal(&nilai_teks, dari, ke, format);
+        return;
+    }
+
+    // Validasi input numerik manual untuk pesan error
+    let nilai = match opsi.tampilan.angka.lokal.urai_nilai(nilai_teks) {
+        Ok(n) => n,
+        Err(galat) => {
+            keluaran::cetak_galat_konversi(format, None, dari, ke, &galat.to_string());
+            process::exit(galat.kode_keluar());
+        }
+    };
 
-                    println!("{} {} = {} {}{}", nilai, satuan_asal.simbol(), hasil, satuan_tujuan.simbol(), string_lainnya);
-                    history::simpan_riwayat(&dari, &ke, nilai, Some(hasil), None);
+    match converter::konversi_teks(nilai, dari, ke) {
+        Ok((asal, tujuan, hasil)) => {
+            match opsi.eksak {
+                Some(eksak) => {
+                    if let Err(galat) = keluaran::cetak_konversi_eksak(opsi.tampilan, nilai, dari, ke, &asal, &tujuan, eksak) {
+                        keluaran::cetak_galat_konversi(format, Some(nilai), dari, ke, &galat.to_string());
+                        process::exit(galat.kode_keluar());
+                    }
                 }
-                Err(pesan) => {
-                    eprintln!("Error: [KESALAHAN] {}", pesan);
-                    history::simpan_riwayat(&dari, &ke, nilai, None, Some(pesan));
-                    process::exit(1);
+                None => {
+                    let tampilan = Tampilan { angka: opsi.tampilan.angka.untuk_teks(nilai_teks), ..opsi.tampilan };
+                    keluaran::cetak_konversi(tampilan, nilai, dari, ke, &asal, hasil, &tujuan)
                 }
             }
+            history::simpan_riwayat(dari, ke, nilai, Some(hasil), None);
         }
-        Commands::List => {
-            println!("Satuan yang didukung:");
-            println!("1. [suhu] celsius, fahrenheit, kelvin");
-            println!("2. [panjang] cm, inch, km, miles");
-            println!("3. [berat] kg, gram, lbs, ounce");
-            println!("4. [volume] liter, gallon, ml");
-            println!("5. [waktu] detik, menit, jam");
-            println!("6. [kecepatan] km/h, mph, m/s");
-            println!("7. [data] byte, kb, mb, gb");
+        Err(galat) => {
+            keluaran::cetak_galat_konversi(format, Some(nilai), dari, ke, &galat.to_string());
+            history::simpan_riwayat(dari, ke, nilai, None, Some(galat.to_string()));
+            process::exit(galat.kode_keluar());
         }
-        Commands::History => {
-            history::tampilkan_riwayat();
+    }
+}
+
+#[cfg(feature = "big-decimal")]
+fn jalankan_konversi_desimal(nilai_teks: &str, dari: &str, ke: &str, format: FormatKeluaran) {
+    use num_traits::ToPrimitive;
+
+    match desimal::konversi_teks(nilai_teks, dari, ke) {
+        Ok((nilai, asal, tujuan, hasil)) => {
+            keluaran::cetak_konversi_desimal(format, &nilai, dari, ke, &asal, &hasil, &tujuan);
+            // Riwayat tetap menyimpan f64; nilai di luar rentangnya dilewati simpan_riwayat
+            if let (Some(nilai), Some(hasil)) = (nilai.to_f64(), hasil.to_f64()) {
+                history::simpan_riwayat(dari, ke, nilai, Some(hasil), None);
+            }
+        }
+        Err(galat) => {
+            keluaran::cetak_galat_konversi(format, None, dari, ke, &galat.to_string());
+            if let Ok(nilai) = converter::urai_nilai(nilai_teks) {
+                history::simpan_riwayat(dari, ke, nilai, None, Some(galat.to_string()));
+            }
+            process::exit(galat.kode_keluar());
         }
     }
 }
+
+#[cfg(not(feature = "big-decimal"))]
+fn jalankan_konversi_desimal(_nilai_teks: &str, _dari: &str, _ke: &str, _format: FormatKeluaran) {
+    eprintln!("{}", pesan::galat(pesan::teks("umum.big_decimal_tanpa_fitur")));
+    process::exit(1);
+}
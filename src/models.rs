@@ -1,49 +1,135 @@
-use serde::{Deserialize, Serialize};
+use crate::awalan::{self, BentukAwalan, JenisAwalan};
+use crate::converter::{self, KonversiError};
+use crate::dimensi::Dimensi;
+use crate::eksak;
+use crate::ekspresi::{self, GalatSatuan};
+use crate::kalkulator;
+use crate::pesan::{self, Bahasa};
+use crate::saran::urai_dengan_saran;
+use num_rational::BigRational;
+use num_traits::{ToPrimitive, Zero};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
+use std::str::FromStr;
+use std::sync::OnceLock;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+// Atmosfer standar (Pa), acuan bawaan satuan tekanan ukur
+pub const ATMOSFER_STANDAR: f64 = 101325.0;
+
+// Tabel definisi satuan bawaan, dimuat saat registry pertama kali dipakai
+const TABEL_BAWAAN: &str = include_str!("satuan_bawaan.json");
+
+// Nama dan alias satuan bawaan per bahasa, dikunci dengan simbol satuan
+const TABEL_BAHASA: [(Bahasa, &str); 2] = [
+    (Bahasa::Id, include_str!("satuan_id.json")),
+    (Bahasa::En, include_str!("satuan_en.json")),
+];
+
+#[derive(Debug, Deserialize)]
+struct EjaanBahasa {
+    nama: String,
+    #[serde(default)]
+    alias: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
-pub enum Satuan {
-    Celsius,
-    Fahrenheit,
-    Kelvin,
-    Cm,
-    Inch,
-    Km,
-    Miles,
-    Kg,
-    Gram,
-    Lbs,
-    Ounce,
-    // Volume
-    Liter,
-    Gallon,
-    Ml,
-    // Waktu
-    Detik,
-    Menit,
-    Jam,
-    // Kecepatan
-    Kmh, // km/h
-    Mph, // mph
-    Ms,  // m/s
-    // Data
-    Byte,
-    KB,
-    MB,
-    GB,
-}
-
-#[derive(Debug, PartialEq)]
 pub enum Kategori {
     Suhu,
     Panjang,
+    Luas,
     Berat,
     Volume,
     Waktu,
     Kecepatan,
+    Tekanan,
+    Energi,
+    Daya,
     Data,
 }
 
+// Satu baris definisi satuan, seperti tertulis di tabel.
+// Nilai dasar dihitung sebagai (nilai + offset) * faktor, dengan faktor terhadap satuan
+// dasar kategori (°C, m, m², kg, L, s, m/s, Pa, J, W, B) atau, untuk satuan tanpa kategori,
+// terhadap satuan SI koheren dari dimensinya (misal newton = "M L T^-2", faktor 1).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DefinisiSatuan {
+    pub nama: String,
+    pub simbol: String,
+    #[serde(default)]
+    pub alias: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kategori: Option<Kategori>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dimensi: Option<Dimensi>,
+    pub faktor: Faktor,
+    #[serde(default)]
+    pub offset: f64,
+    // Awalan SI/biner yang boleh dipasang ("km", "MiB")
+    #[serde(default)]
+    pub awalan: JenisAwalan,
+    // Tekanan ukur (psig, barg): offset diisi dari tekanan atmosfer acuan, bukan dari tabel
+    #[serde(default)]
+    pub ukur: bool,
+    // Sistem satuan adat (US/imperial) dan ejaan yang dipakai bersama varian sistem lain
+    // ("gal", "pint"); ejaan itu diartikan menurut sistem bawaan registry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sistem: Option<Sistem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ambigu: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Sistem {
+    #[default]
+    Us,
+    Imperial,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Satuan {
+    // Nama kanonik dari tabel; nama_lokal menurut --lang hanya untuk tampilan teks
+    nama: String,
+    nama_lokal: Option<String>,
+    simbol: String,
+    alias: Vec<String>,
+    dimensi: Dimensi,
+    // Faktor terhadap satuan SI koheren dari dimensinya (m, kg, s, m³, ...)
+    faktor: Faktor,
+    offset: f64,
+    // Offset yang sama sebagai pecahan persis untuk --exact dan --big-decimal; offset tekanan ukur
+    // (atmosfer / faktor) tidak berhingga dalam desimal sehingga tidak dapat diturunkan dari f64
+    offset_persis: BigRational,
+    awalan: JenisAwalan,
+    ukur: bool,
+    sistem: Option<Sistem>,
+}
+
+// Faktor disimpan sebagai pecahan bilangan bulat persis agar 5/9, 1/1000 atau hasil kali
+// faktor seperti psi·in² tidak menumpuk galat pembulatan; pembilang dan penyebut f64 disimpan
+// sekalian untuk konversi biasa
+#[derive(Debug, Clone, PartialEq)]
+pub struct Faktor {
+    persis: BigRational,
+    pembilang: f64,
+    penyebut: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UnitRegistry {
+    satuan: Vec<Satuan>,
+    // Ejaan persis (peka huruf besar) dan ejaan huruf kecil
+    persis: HashMap<String, usize>,
+    indeks: HashMap<String, usize>,
+    // Ejaan huruf kecil yang dipakai beberapa varian sistem, dipilih menurut `sistem`
+    ambigu: HashMap<String, Vec<usize>>,
+    sistem: Sistem,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct CatatanKonversi {
     pub satuan_asal: String,
@@ -55,134 +141,206 @@ pub struct CatatanKonversi {
     pub pesan_error: Option<String>,
 }
 
-impl Satuan {
-    pub fn dari_str(s: &str) -> Option<Satuan> {
-        match s.to_lowercase().as_str() {
-            "celsius" => Some(Satuan::Celsius),
-            "fahrenheit" => Some(Satuan::Fahrenheit),
-            "kelvin" => Some(Satuan::Kelvin),
-            "cm" => Some(Satuan::Cm),
-            "inch" => Some(Satuan::Inch),
-            "km" => Some(Satuan::Km),
-            "miles" => Some(Satuan::Miles),
-            "kg" => Some(Satuan::Kg),
-            "gram" => Some(Satuan::Gram),
-            "lbs" => Some(Satuan::Lbs),
-            "ounce" => Some(Satuan::Ounce),
-            "liter" | "l" => Some(Satuan::Liter),
-            "gallon" | "gal" => Some(Satuan::Gallon),
-            "ml" => Some(Satuan::Ml),
-            "detik" | "second" | "sec" | "s" => Some(Satuan::Detik),
-            "menit" | "minute" | "min" | "m" => Some(Satuan::Menit),
-            "jam" | "hour" | "h" => Some(Satuan::Jam),
-            "km/h" | "kmh" => Some(Satuan::Kmh),
-            "mph" => Some(Satuan::Mph),
-            "m/s" | "ms" => Some(Satuan::Ms),
-            "byte" | "b" => Some(Satuan::Byte),
-            "kb" => Some(Satuan::KB),
-            "mb" => Some(Satuan::MB),
-            "gb" => Some(Satuan::GB),
-            _ => None,
+// Nilai beserta satuannya, misal 12.5 kg
+#[derive(Debug, Clone)]
+pub struct Kuantitas {
+    pub nilai: f64,
+    pub satuan: Satuan,
+}
+
+impl Faktor {
+    fn baru(persis: BigRational) -> Faktor {
+        let pembilang = persis.numer().to_f64().unwrap_or(f64::INFINITY);
+        let penyebut = persis.denom().to_f64().unwrap_or(f64::INFINITY);
+        Faktor { persis, pembilang, penyebut }
+    }
+
+    pub fn nilai(&self) -> f64 {
+        self.pembilang / self.penyebut
+    }
+
+    // Pembilang dan penyebut dalam bentuk paling sederhana, misal 473176473/125000000 untuk galon AS
+    pub fn pembilang(&self) -> f64 {
+        self.pembilang
+    }
+
+    pub fn penyebut(&self) -> f64 {
+        self.penyebut
+    }
+
+    pub fn persis(&self) -> &BigRational {
+        &self.persis
+    }
+
+    pub fn kali(&self, lain: &Faktor) -> Faktor {
+        Faktor::baru(&self.persis * &lain.persis)
+    }
+
+    pub fn bagi(&self, lain: &Faktor) -> Faktor {
+        Faktor::baru(&self.persis / &lain.persis)
+    }
+
+    pub fn pangkat(&self, n: i8) -> Faktor {
+        Faktor::baru(self.persis.pow(i32::from(n)))
+    }
+
+    // Angka desimal ("0.0254", "1e-6") atau pecahan ("5/9", "1/3.6"), dibaca langsung tanpa f64
+    pub fn dari_teks(teks: &str) -> Option<Faktor> {
+        let desimal = |t: &str| eksak::dari_teks(t.trim());
+        let persis = match teks.split_once('/') {
+            Some((pembilang, penyebut)) => {
+                let penyebut = desimal(penyebut)?;
+                if penyebut.is_zero() {
+                    return None;
+                }
+                desimal(pembilang)? / penyebut
+            }
+            None => desimal(teks)?,
+        };
+        Some(Faktor::baru(persis))
+    }
+}
+
+impl From<f64> for Faktor {
+    // Representasi desimal terpendek ("0.0254") dijadikan pecahan 127/5000
+    fn from(n: f64) -> Faktor {
+        Faktor::baru(eksak::dari_f64(n))
+    }
+}
+
+// Faktor boleh ditulis sebagai angka (0.0254) atau pecahan ("5/9")
+impl<'de> Deserialize<'de> for Faktor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Faktor, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Bentuk {
+            Angka(f64),
+            Teks(String),
+        }
+
+        match Bentuk::deserialize(deserializer)? {
+            Bentuk::Angka(n) => Ok(Faktor::from(n)),
+            Bentuk::Teks(teks) => Faktor::dari_teks(&teks)
+                .ok_or_else(|| serde::de::Error::custom(pesan::format("definisi.faktor_tidak_valid", &[&teks]))),
         }
     }
+}
 
-    pub fn kategori(&self) -> Kategori {
-        match self {
-            Satuan::Celsius | Satuan::Fahrenheit | Satuan::Kelvin => Kategori::Suhu,
-            Satuan::Cm | Satuan::Inch | Satuan::Km | Satuan::Miles => Kategori::Panjang,
-            Satuan::Kg | Satuan::Gram | Satuan::Lbs | Satuan::Ounce => Kategori::Berat,
-            Satuan::Liter | Satuan::Gallon | Satuan::Ml => Kategori::Volume,
-            Satuan::Detik | Satuan::Menit | Satuan::Jam => Kategori::Waktu,
-            Satuan::Kmh | Satuan::Mph | Satuan::Ms => Kategori::Kecepatan,
-            Satuan::Byte | Satuan::KB | Satuan::MB | Satuan::GB => Kategori::Data,
+impl Serialize for Faktor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.persis.is_integer() {
+            serializer.serialize_str(&self.persis.numer().to_string())
+        } else {
+            serializer.serialize_str(&format!("{}/{}", self.persis.numer(), self.persis.denom()))
         }
     }
+}
 
-    pub fn simbol(&self) -> &'static str {
-        match self {
-            Satuan::Celsius => "°C",
-            Satuan::Fahrenheit => "°F",
-            Satuan::Kelvin => "K",
-            Satuan::Cm => "cm",
-            Satuan::Inch => "inch",
-            Satuan::Km => "km",
-            Satuan::Miles => "miles",
-            Satuan::Kg => "kg",
-            Satuan::Gram => "g",
-            Satuan::Lbs => "lbs",
-            Satuan::Ounce => "oz",
-            Satuan::Liter => "L",
-            Satuan::Gallon => "gal",
-            Satuan::Ml => "ml",
-            Satuan::Detik => "s",
-            Satuan::Menit => "min",
-            Satuan::Jam => "h",
-            Satuan::Kmh => "km/h",
-            Satuan::Mph => "mph",
-            Satuan::Ms => "m/s",
-            Satuan::Byte => "B",
-            Satuan::KB => "KB",
-            Satuan::MB => "MB",
-            Satuan::GB => "GB",
-        }
-    }
-    
-    pub fn nama(&self) -> &'static str {
-        match self {
-            Satuan::Celsius => "celsius",
-            Satuan::Fahrenheit => "fahrenheit",
-            Satuan::Kelvin => "kelvin",
-            Satuan::Cm => "cm",
-            Satuan::Inch => "inch",
-            Satuan::Km => "km",
-            Satuan::Miles => "miles",
-            Satuan::Kg => "kg",
-            Satuan::Gram => "gram",
-            Satuan::Lbs => "lbs",
-            Satuan::Ounce => "ounce",
-            Satuan::Liter => "liter",
-            Satuan::Gallon => "gallon",
-            Satuan::Ml => "ml",
-            Satuan::Detik => "detik",
-            Satuan::Menit => "menit",
-            Satuan::Jam => "jam",
-            Satuan::Kmh => "km/h",
-            Satuan::Mph => "mph",
-            Satuan::Ms => "m/s",
-            Satuan::Byte => "byte",
-            Satuan::KB => "kb",
-            Satuan::MB => "mb",
-            Satuan::GB => "gb",
+impl Satuan {
+    pub fn dari_str(s: &str) -> Option<Satuan> {
+        UnitRegistry::global().urai(s).ok()
+    }
+
+    // Satuan gabungan dari ekspresi seperti "kg*m/s^2"
+    pub(crate) fn turunan(simbol: &str, dimensi: Dimensi, faktor: Faktor) -> Satuan {
+        Satuan {
+            nama: simbol.to_string(),
+            nama_lokal: None,
+            simbol: simbol.to_string(),
+            alias: Vec::new(),
+            dimensi,
+            faktor,
+            offset: 0.0,
+            offset_persis: BigRational::zero(),
+            awalan: JenisAwalan::Tidak,
+            ukur: false,
+            sistem: None,
         }
     }
 
+    // Satuan SI koheren untuk sebuah dimensi, misal "kg·m²·s⁻²"
+    pub fn si(dimensi: Dimensi) -> Satuan {
+        Satuan::turunan(&dimensi.simbol_si(), dimensi, Faktor::from(1.0))
+    }
+
+    // Kategori ditentukan dari dimensi; satuan seperti newton tidak punya kategori
+    pub fn kategori(&self) -> Option<Kategori> {
+        Kategori::dari_dimensi(self.dimensi)
+    }
+
+    pub fn dimensi(&self) -> Dimensi {
+        self.dimensi
+    }
+
+    pub fn simbol(&self) -> &str {
+        &self.simbol
+    }
+
+    // Nama untuk pesan dan tampilan teks, dalam bahasa aktif bila tersedia
+    pub fn nama(&self) -> &str {
+        self.nama_lokal.as_deref().unwrap_or(&self.nama)
+    }
+
+    // Nama dari tabel bawaan, tidak bergantung --lang; dipakai keluaran JSON dan TSV
+    pub fn nama_kanonik(&self) -> &str {
+        &self.nama
+    }
+
+    pub fn alias(&self) -> &[String] {
+        &self.alias
+    }
+
+    pub fn sistem(&self) -> Option<Sistem> {
+        self.sistem
+    }
+
+    pub fn awalan(&self) -> JenisAwalan {
+        self.awalan
+    }
+
+    pub fn faktor(&self) -> f64 {
+        self.faktor.nilai()
+    }
+
+    pub fn faktor_pecahan(&self) -> &Faktor {
+        &self.faktor
+    }
+
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    pub fn offset_persis(&self) -> &BigRational {
+        &self.offset_persis
+    }
+
+    // Nilai dalam satuan SI koheren dimensinya (meter, kilogram, m³, ...); suhu dalam °C
+    pub fn ke_dasar(&self, nilai: f64) -> f64 {
+        (nilai + self.offset) * self.faktor.pembilang() / self.faktor.penyebut()
+    }
+
+    pub fn dari_dasar(&self, nilai_dasar: f64) -> f64 {
+        nilai_dasar * self.faktor.penyebut() / self.faktor.pembilang() - self.offset
+    }
+
+    // Satuan yang sama nilainya walau ejaannya berbeda ("km" dan "kilometer")
+    pub fn setara(&self, lain: &Satuan) -> bool {
+        self.dimensi == lain.dimensi
+            && self.faktor() == lain.faktor()
+            && self.offset == lain.offset
+    }
+
     pub fn semua() -> Vec<Satuan> {
-        vec![
-            Satuan::Celsius,
-            Satuan::Fahrenheit,
-            Satuan::Kelvin,
-            Satuan::Cm,
-            Satuan::Inch,
-            Satuan::Km,
-            Satuan::Miles,
-            Satuan::Kg,
-            Satuan::Gram,
-            Satuan::Lbs,
-            Satuan::Ounce,
-            Satuan::Liter,
-            Satuan::Gallon,
-            Satuan::Ml,
-            Satuan::Detik,
-            Satuan::Menit,
-            Satuan::Jam,
-            Satuan::Kmh,
-            Satuan::Mph,
-            Satuan::Ms,
-            Satuan::Byte,
-            Satuan::KB,
-            Satuan::MB,
-            Satuan::GB,
-        ]
+        UnitRegistry::global().semua().to_vec()
+    }
+}
+
+impl Sistem {
+    pub fn kode(&self) -> &'static str {
+        match self {
+            Sistem::Us => "us",
+            Sistem::Imperial => "imperial",
+        }
     }
 }
 
@@ -191,23 +349,539 @@ impl Kategori {
         match self {
             Kategori::Suhu => "suhu",
             Kategori::Panjang => "panjang",
+            Kategori::Luas => "luas",
             Kategori::Berat => "berat",
             Kategori::Volume => "volume",
             Kategori::Waktu => "waktu",
             Kategori::Kecepatan => "kecepatan",
+            Kategori::Tekanan => "tekanan",
+            Kategori::Energi => "energi",
+            Kategori::Daya => "daya",
             Kategori::Data => "data",
         }
     }
 
-    pub fn satuan_satuan(&self) -> Vec<Satuan> {
+    // Nama kategori dalam bahasa antarmuka; nama() tetap dipakai di keluaran JSON/TSV
+    pub fn nama_tampilan(&self) -> &'static str {
+        pesan::teks(match self {
+            Kategori::Suhu => "kategori.suhu",
+            Kategori::Panjang => "kategori.panjang",
+            Kategori::Luas => "kategori.luas",
+            Kategori::Berat => "kategori.berat",
+            Kategori::Volume => "kategori.volume",
+            Kategori::Waktu => "kategori.waktu",
+            Kategori::Kecepatan => "kategori.kecepatan",
+            Kategori::Tekanan => "kategori.tekanan",
+            Kategori::Energi => "kategori.energi",
+            Kategori::Daya => "kategori.daya",
+            Kategori::Data => "kategori.data",
+        })
+    }
+
+    pub fn dimensi(&self) -> Dimensi {
         match self {
-            Kategori::Suhu => vec![Satuan::Celsius, Satuan::Fahrenheit, Satuan::Kelvin],
-            Kategori::Panjang => vec![Satuan::Cm, Satuan::Inch, Satuan::Km, Satuan::Miles],
-            Kategori::Berat => vec![Satuan::Kg, Satuan::Gram, Satuan::Lbs, Satuan::Ounce],
-            Kategori::Volume => vec![Satuan::Liter, Satuan::Gallon, Satuan::Ml],
-            Kategori::Waktu => vec![Satuan::Detik, Satuan::Menit, Satuan::Jam],
-            Kategori::Kecepatan => vec![Satuan::Kmh, Satuan::Mph, Satuan::Ms],
-            Kategori::Data => vec![Satuan::Byte, Satuan::KB, Satuan::MB, Satuan::GB],
+            Kategori::Suhu => Dimensi::SUHU,
+            Kategori::Panjang => Dimensi::PANJANG,
+            Kategori::Luas => Dimensi([2, 0, 0, 0, 0, 0, 0, 0]),
+            Kategori::Berat => Dimensi::MASSA,
+            Kategori::Volume => Dimensi([3, 0, 0, 0, 0, 0, 0, 0]),
+            Kategori::Waktu => Dimensi::WAKTU,
+            Kategori::Kecepatan => Dimensi([1, 0, -1, 0, 0, 0, 0, 0]),
+            Kategori::Tekanan => Dimensi([-1, 1, -2, 0, 0, 0, 0, 0]),
+            Kategori::Energi => Dimensi([2, 1, -2, 0, 0, 0, 0, 0]),
+            Kategori::Daya => Dimensi([2, 1, -3, 0, 0, 0, 0, 0]),
+            Kategori::Data => Dimensi::INFORMASI,
+        }
+    }
+
+    pub fn dari_dimensi(dimensi: Dimensi) -> Option<Kategori> {
+        Kategori::semua().into_iter().find(|k| k.dimensi() == dimensi)
+    }
+
+    // Satuan dasar kategori terhadap satuan SI koheren (1 L = 1/1000 m³)
+    pub fn faktor_dasar(&self) -> Faktor {
+        Faktor::from(1.0).bagi(&Faktor::from(self.penyebut_dasar()))
+    }
+
+    // Penyebut faktor_dasar; const agar dapat dipakai satuan tetap di besaran.rs
+    pub const fn penyebut_dasar(&self) -> f64 {
+        match self {
+            Kategori::Volume => 1000.0,
+            _ => 1.0,
+        }
+    }
+
+    pub fn semua() -> Vec<Kategori> {
+        vec![
+            Kategori::Suhu,
+            Kategori::Panjang,
+            Kategori::Luas,
+            Kategori::Berat,
+            Kategori::Volume,
+            Kategori::Waktu,
+            Kategori::Kecepatan,
+            Kategori::Tekanan,
+            Kategori::Energi,
+            Kategori::Daya,
+            Kategori::Data,
+        ]
+    }
+
+    pub fn satuan_satuan(&self) -> Vec<Satuan> {
+        UnitRegistry::global()
+            .dalam_kategori(*self)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+}
+
+impl UnitRegistry {
+    pub fn bawaan() -> UnitRegistry {
+        let daftar: Vec<DefinisiSatuan> =
+            serde_json::from_str(TABEL_BAWAAN).expect("Tabel satuan bawaan tidak valid");
+        let tabel_bahasa: Vec<(Bahasa, HashMap<String, EjaanBahasa>)> = TABEL_BAHASA
+            .iter()
+            .map(|&(bahasa, teks)| {
+                let isi = serde_json::from_str(teks)
+                    .unwrap_or_else(|e| panic!("Tabel nama satuan '{}' tidak valid: {}", bahasa.kode(), e));
+                (bahasa, isi)
+            })
+            .collect();
+        for (bahasa, isi) in &tabel_bahasa {
+            if let Some(simbol) = isi.keys().find(|simbol| !daftar.iter().any(|d| &d.simbol == *simbol)) {
+                panic!("Tabel nama satuan '{}' memuat simbol '{}' yang tidak ada", bahasa.kode(), simbol);
+            }
+        }
+
+        let mut registry = UnitRegistry::default();
+        for mut definisi in daftar {
+            let nama_lokal = terapkan_bahasa(&mut definisi, &tabel_bahasa);
+            registry.tambah(definisi).expect("Tabel satuan bawaan tidak valid");
+            if let Some(satuan) = registry.satuan.last_mut() {
+                satuan.nama_lokal = nama_lokal;
+            }
         }
+        registry
     }
+
+    // Registry yang dipakai CLI dan Satuan::dari_str
+    pub fn global() -> &'static UnitRegistry {
+        REGISTRY.get_or_init(UnitRegistry::bawaan)
+    }
+
+    // Pasang registry (misal dengan satuan dari berkas pengguna) sebelum registry global dipakai
+    pub fn pasang_global(registry: UnitRegistry) -> Result<(), Box<UnitRegistry>> {
+        REGISTRY.set(registry).map_err(Box::new)
+    }
+
+    pub fn tambah(&mut self, definisi: DefinisiSatuan) -> Result<(), String> {
+        if definisi.nama.trim().is_empty() || definisi.simbol.trim().is_empty() {
+            return Err(pesan::format("definisi.tanpa_nama", &[&definisi.nama]));
+        }
+        let faktor = definisi.faktor.nilai();
+        if faktor == 0.0 || !faktor.is_finite() {
+            return Err(pesan::format("definisi.faktor_nol", &[&definisi.nama, &faktor]));
+        }
+        if !definisi.offset.is_finite() {
+            return Err(pesan::format("definisi.offset_tidak_valid", &[&definisi.nama]));
+        }
+        let (dimensi, faktor) = match (definisi.kategori, definisi.dimensi) {
+            (Some(kategori), dimensi) => {
+                if dimensi.is_some_and(|d| d != kategori.dimensi()) {
+                    return Err(pesan::format(
+                        "definisi.dimensi_kategori",
+                        &[&definisi.nama, &kategori.nama(), &kategori.dimensi()],
+                    ));
+                }
+                (kategori.dimensi(), definisi.faktor.kali(&kategori.faktor_dasar()))
+            }
+            (None, Some(dimensi)) => (dimensi, definisi.faktor),
+            (None, None) => {
+                return Err(pesan::format("definisi.tanpa_kategori", &[&definisi.nama]))
+            }
+        };
+        if definisi.ukur && dimensi != Kategori::Tekanan.dimensi() {
+            return Err(pesan::format("definisi.ukur_bukan_tekanan", &[&definisi.nama]));
+        }
+        let (offset, offset_persis) = if definisi.ukur {
+            let persis = eksak::dari_f64(ATMOSFER_STANDAR) / faktor.persis();
+            (persis.to_f64().unwrap_or(f64::NAN), persis)
+        } else {
+            (definisi.offset, eksak::dari_f64(definisi.offset))
+        };
+
+        let ambigu: Vec<String> = definisi.ambigu.iter().map(|e| e.to_lowercase()).collect();
+        if !ambigu.is_empty() && definisi.sistem.is_none() {
+            return Err(pesan::format("definisi.ambigu_tanpa_sistem", &[&definisi.nama]));
+        }
+        for ejaan in &ambigu {
+            let pemakai = self.indeks.get(ejaan).copied().or_else(|| {
+                self.ambigu.get(ejaan)?.iter().copied().find(|&i| self.satuan[i].sistem == definisi.sistem)
+            });
+            if let Some(i) = pemakai {
+                return Err(pesan::format("definisi.ejaan_dipakai", &[ejaan, &definisi.nama, &self.satuan[i].nama]));
+            }
+        }
+
+        // Ejaan ambigu tidak masuk indeks biasa agar selalu melewati pemilihan sistem
+        let mut persis: Vec<&String> = Vec::new();
+        let mut kunci: Vec<String> = Vec::new();
+        for ejaan in std::iter::once(&definisi.nama)
+            .chain(std::iter::once(&definisi.simbol))
+            .chain(definisi.alias.iter())
+        {
+            let kecil = ejaan.to_lowercase();
+            if ambigu.contains(&kecil) {
+                continue;
+            }
+            let pemakai = self.indeks.get(&kecil).copied().or_else(|| self.ambigu.get(&kecil).map(|d| d[0]));
+            if let Some(i) = pemakai {
+                return Err(pesan::format("definisi.ejaan_dipakai", &[&kecil, &definisi.nama, &self.satuan[i].nama]));
+            }
+            persis.push(ejaan);
+            if !kunci.contains(&kecil) {
+                kunci.push(kecil);
+            }
+        }
+
+        let posisi = self.satuan.len();
+        for ejaan in persis {
+            self.persis.insert(ejaan.clone(), posisi);
+        }
+        for ejaan in kunci {
+            self.indeks.insert(ejaan, posisi);
+        }
+        for ejaan in ambigu {
+            self.ambigu.entry(ejaan).or_default().push(posisi);
+        }
+        self.satuan.push(Satuan {
+            nama: definisi.nama,
+            nama_lokal: None,
+            simbol: definisi.simbol,
+            alias: definisi.alias,
+            dimensi,
+            faktor,
+            offset,
+            offset_persis,
+            awalan: definisi.awalan,
+            ukur: definisi.ukur,
+            sistem: definisi.sistem,
+        });
+        Ok(())
+    }
+
+    // Tekanan atmosfer acuan (Pa) untuk satuan ukur: nilai ukur + atmosfer = nilai mutlak
+    pub fn atur_atmosfer(&mut self, pa: &BigRational) {
+        for satuan in self.satuan.iter_mut().filter(|s| s.ukur) {
+            satuan.offset_persis = pa / satuan.faktor.persis();
+            satuan.offset = satuan.offset_persis.to_f64().unwrap_or(f64::NAN);
+        }
+    }
+
+    // Sistem yang dipakai untuk ejaan ambigu seperti "gal" atau "pint"
+    pub fn atur_sistem(&mut self, sistem: Sistem) {
+        self.sistem = sistem;
+    }
+
+    // Urutan pencarian: ejaan persis, ejaan ambigu menurut sistem bawaan, satuan berawalan
+    // ("mm", "kilogram", "GiB"), ejaan tanpa memperhatikan huruf besar ("KG", "Celsius"),
+    // lalu bentuk jamak ("hours")
+    pub fn cari(&self, s: &str) -> Option<Satuan> {
+        let s = s.trim();
+        if let Some(&i) = self.persis.get(s) {
+            return Some(self.satuan[i].clone());
+        }
+        if let Some(daftar) = self.ambigu.get(&s.to_lowercase()) {
+            return Some(self.satuan[self.pilih_sistem(daftar)].clone());
+        }
+        if let Some(satuan) = self.cari_berawalan(s) {
+            return Some(satuan);
+        }
+        if let Some(&i) = self.indeks.get(&s.to_lowercase()) {
+            return Some(self.satuan[i].clone());
+        }
+        match s.strip_suffix('s') {
+            Some(tunggal) if tunggal.chars().count() > 2 => self.cari(tunggal),
+            _ => None,
+        }
+    }
+
+    fn cari_berawalan(&self, s: &str) -> Option<Satuan> {
+        for (awalan, bentuk, sisa) in awalan::pisahkan(s) {
+            let dasar = match bentuk {
+                // Simbol awalan hanya digabung dengan simbol atau alias ("k" + "m")
+                BentukAwalan::Simbol => self.persis.get(sisa).map(|&i| &self.satuan[i]).filter(|u| {
+                    u.simbol == sisa || u.alias.iter().any(|a| a == sisa)
+                }),
+                // Nama awalan digabung dengan nama atau alias ("kilo" + "meter")
+                BentukAwalan::Nama => self.indeks.get(&sisa.to_lowercase()).map(|&i| &self.satuan[i]).filter(|u| {
+                    u.nama.eq_ignore_ascii_case(sisa) || u.alias.iter().any(|a| a.eq_ignore_ascii_case(sisa))
+                }),
+            };
+
+            if let Some(dasar) = dasar.filter(|u| u.awalan.menerima(awalan)) {
+                // Simbol hasil yang sudah dimiliki satuan lain ("Mb" -> "MB") hanya diterima
+                // bila maknanya sama; satuan itulah yang dipakai agar tampilannya tidak ganda
+                let simbol = format!("{}{}", awalan.simbol_utama(), dasar.simbol);
+                let pengali = Faktor::from(awalan.faktor);
+                let faktor = dasar.faktor.kali(&pengali);
+                if let Some(&i) = self.persis.get(&simbol) {
+                    let pemilik = &self.satuan[i];
+                    if pemilik.dimensi == dasar.dimensi && sama_dekat(pemilik.faktor.nilai(), faktor.nilai()) {
+                        return Some(pemilik.clone());
+                    }
+                    continue;
+                }
+                return Some(Satuan {
+                    nama: format!("{}{}", awalan.nama_tampilan(), dasar.nama),
+                    nama_lokal: dasar.nama_lokal.as_ref().map(|nama| format!("{}{}", awalan.nama_tampilan(), nama)),
+                    simbol,
+                    alias: Vec::new(),
+                    dimensi: dasar.dimensi,
+                    faktor,
+                    offset: dasar.offset / awalan.faktor,
+                    offset_persis: &dasar.offset_persis / pengali.persis(),
+                    awalan: JenisAwalan::Tidak,
+                    ukur: false,
+                    sistem: None,
+                });
+            }
+        }
+        None
+    }
+
+    // Untuk ejaan ambigu ("gal", "pints"): satuan yang dipilih dan satu varian sistem lainnya
+    pub fn tafsiran_ambigu(&self, s: &str) -> Option<(&Satuan, &Satuan)> {
+        let s = s.trim();
+        if self.persis.contains_key(s) {
+            return None;
+        }
+        let kecil = s.to_lowercase();
+        let daftar = self.ambigu.get(&kecil).or_else(|| {
+            let tunggal = kecil.strip_suffix('s').filter(|t| t.chars().count() > 2)?;
+            self.ambigu.get(tunggal)
+        })?;
+        let pilihan = self.pilih_sistem(daftar);
+        let lain = daftar.iter().copied().find(|&i| i != pilihan)?;
+        Some((&self.satuan[pilihan], &self.satuan[lain]))
+    }
+
+    // Alias pertama yang hanya menunjuk satuan ini ("gal_us", "gal_imp"), atau simbolnya
+    pub fn ejaan_tegas<'a>(&self, satuan: &'a Satuan) -> &'a str {
+        satuan
+            .alias
+            .iter()
+            .chain(std::iter::once(&satuan.simbol))
+            .find(|e| !self.ambigu.contains_key(&e.to_lowercase()))
+            .unwrap_or(&satuan.simbol)
+    }
+
+    fn pilih_sistem(&self, daftar: &[usize]) -> usize {
+        daftar.iter().copied().find(|&i| self.satuan[i].sistem == Some(self.sistem)).unwrap_or(daftar[0])
+    }
+
+    // Satuan tunggal atau ekspresi gabungan ("km/min", "kg*m/s^2", "L/100km")
+    pub fn urai(&self, s: &str) -> Result<Satuan, GalatSatuan> {
+        ekspresi::urai(self, s)
+    }
+
+    pub fn semua(&self) -> &[Satuan] {
+        &self.satuan
+    }
+
+    pub fn dalam_kategori(&self, kategori: Kategori) -> Vec<&Satuan> {
+        self.satuan.iter().filter(|s| s.kategori() == Some(kategori)).collect()
+    }
+}
+
+static REGISTRY: OnceLock<UnitRegistry> = OnceLock::new();
+
+// Nama dan alias dari semua bahasa menjadi ejaan yang dikenali ("metre", "sentimeter", "pon");
+// nama tampilan menurut bahasa antarmuka dikembalikan terpisah agar nama kanonik tetap utuh
+fn terapkan_bahasa(definisi: &mut DefinisiSatuan, tabel: &[(Bahasa, HashMap<String, EjaanBahasa>)]) -> Option<String> {
+    let mut nama_lokal = None;
+    let mut ejaan = Vec::new();
+    for (bahasa, isi) in tabel {
+        let Some(lokal) = isi.get(&definisi.simbol) else { continue };
+        if *bahasa == pesan::bahasa() {
+            nama_lokal = Some(lokal.nama.clone());
+        }
+        ejaan.push(lokal.nama.clone());
+        ejaan.extend(lokal.alias.iter().cloned());
+    }
+    for e in ejaan {
+        if e != definisi.nama && e != definisi.simbol && !definisi.alias.contains(&e) {
+            definisi.alias.push(e);
+        }
+    }
+    nama_lokal.filter(|nama| *nama != definisi.nama)
+}
+
+impl Kuantitas {
+    pub fn new(nilai: f64, satuan: Satuan) -> Kuantitas {
+        Kuantitas { nilai, satuan }
+    }
+
+    // Kuantitas yang sama dalam satuan lain, misal (1 km).to(m) = 1000 m
+    pub fn to(&self, satuan: &Satuan) -> Result<Kuantitas, KonversiError> {
+        let nilai = converter::konversi(self.nilai, &self.satuan, satuan)?;
+        Ok(Kuantitas::new(nilai, satuan.clone()))
+    }
+
+    // Nilai dalam satuan SI koheren, dipakai untuk perbandingan
+    fn nilai_dasar(&self) -> f64 {
+        self.satuan.ke_dasar(self.nilai)
+    }
+
+    // Satuan gabungan untuk perkalian/pembagian; offset diabaikan seperti pada ekspresi satuan
+    fn gabung(&self, lain: &Satuan, operator: &str, faktor: Faktor, dimensi: Option<Dimensi>) -> Result<Satuan, KonversiError> {
+        let bungkus = |simbol: &str| {
+            if simbol.contains(['/', '·', '*']) {
+                format!("({})", simbol)
+            } else {
+                simbol.to_string()
+            }
+        };
+        let simbol = format!("{}{}{}", bungkus(self.satuan.simbol()), operator, bungkus(lain.simbol()));
+        match dimensi {
+            Some(dimensi) => Ok(Satuan::turunan(&simbol, dimensi, faktor)),
+            None => Err(KonversiError::SatuanTidakValid {
+                peran: "kuantitas",
+                input: simbol,
+                detail: pesan::teks("ekspresi.pangkat_terlalu_besar").to_string(),
+            }),
+        }
+    }
+}
+
+// Penjumlahan/pengurangan hanya untuk dimensi yang sama; hasil dalam satuan operan kiri
+impl Add for Kuantitas {
+    type Output = Result<Kuantitas, KonversiError>;
+
+    fn add(self, lain: Kuantitas) -> Self::Output {
+        let lain = lain.to(&self.satuan)?;
+        Ok(Kuantitas::new(self.nilai + lain.nilai, self.satuan))
+    }
+}
+
+impl Sub for Kuantitas {
+    type Output = Result<Kuantitas, KonversiError>;
+
+    fn sub(self, lain: Kuantitas) -> Self::Output {
+        let lain = lain.to(&self.satuan)?;
+        Ok(Kuantitas::new(self.nilai - lain.nilai, self.satuan))
+    }
+}
+
+// 2 km * 3 h = 6 km·h; satuan digabung tanpa konversi. Gagal hanya bila pangkat dimensi
+// hasilnya di luar rentang
+impl Mul for Kuantitas {
+    type Output = Result<Kuantitas, KonversiError>;
+
+    fn mul(self, lain: Kuantitas) -> Self::Output {
+        let satuan = self.gabung(
+            &lain.satuan,
+            "·",
+            self.satuan.faktor_pecahan().kali(lain.satuan.faktor_pecahan()),
+            self.satuan.dimensi().kali(lain.satuan.dimensi()),
+        )?;
+        Ok(Kuantitas::new(self.nilai * lain.nilai, satuan))
+    }
+}
+
+impl Div for Kuantitas {
+    type Output = Result<Kuantitas, KonversiError>;
+
+    fn div(self, lain: Kuantitas) -> Self::Output {
+        let satuan = self.gabung(
+            &lain.satuan,
+            "/",
+            self.satuan.faktor_pecahan().bagi(lain.satuan.faktor_pecahan()),
+            self.satuan.dimensi().bagi(lain.satuan.dimensi()),
+        )?;
+        Ok(Kuantitas::new(self.nilai / lain.nilai, satuan))
+    }
+}
+
+impl Mul<f64> for Kuantitas {
+    type Output = Kuantitas;
+
+    fn mul(self, skalar: f64) -> Kuantitas {
+        Kuantitas::new(self.nilai * skalar, self.satuan)
+    }
+}
+
+impl Mul<Kuantitas> for f64 {
+    type Output = Kuantitas;
+
+    fn mul(self, kuantitas: Kuantitas) -> Kuantitas {
+        kuantitas * self
+    }
+}
+
+impl Div<f64> for Kuantitas {
+    type Output = Kuantitas;
+
+    fn div(self, skalar: f64) -> Kuantitas {
+        Kuantitas::new(self.nilai / skalar, self.satuan)
+    }
+}
+
+// Perbandingan lintas satuan sedimensi (1 km > 500 m); beda dimensi tidak dapat dibandingkan
+impl PartialEq for Kuantitas {
+    fn eq(&self, lain: &Kuantitas) -> bool {
+        self.partial_cmp(lain) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Kuantitas {
+    fn partial_cmp(&self, lain: &Kuantitas) -> Option<Ordering> {
+        if self.satuan.dimensi() != lain.satuan.dimensi() {
+            return None;
+        }
+        self.nilai_dasar().partial_cmp(&lain.nilai_dasar())
+    }
+}
+
+// "12.5 kg", "-40°F", "3 km/h"
+impl FromStr for Kuantitas {
+    type Err = KonversiError;
+
+    fn from_str(s: &str) -> Result<Kuantitas, KonversiError> {
+        let (nilai, satuan) =
+            kalkulator::pisahkan_nilai_satuan(s).ok_or_else(|| KonversiError::NilaiTidakValid(s.to_string()))?;
+        if !nilai.is_finite() {
+            return Err(KonversiError::NilaiTidakHingga);
+        }
+        Ok(Kuantitas::new(nilai, urai_dengan_saran("kuantitas", satuan)?))
+    }
+}
+
+impl fmt::Display for Kuantitas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(presisi) => write!(f, "{:.*} {}", presisi, self.nilai, self.satuan.simbol()),
+            None => write!(f, "{} {}", self.nilai, self.satuan.simbol()),
+        }
+    }
+}
+
+// Diserialisasi sebagai teks "12.5 kg" agar dapat dibaca kembali lewat FromStr
+impl Serialize for Kuantitas {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Kuantitas {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Kuantitas, D::Error> {
+        let teks = String::deserialize(deserializer)?;
+        teks.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+// Dua faktor dianggap sama bila selisih relatifnya hanya galat pembulatan f64
+fn sama_dekat(a: f64, b: f64) -> bool {
+    (a - b).abs() <= a.abs().max(b.abs()) * 1e-12
 }
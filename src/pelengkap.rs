@@ -0,0 +1,103 @@
+// Skrip pelengkapan shell. Bagian statis dibuat clap_complete dari definisi Cli; nilai
+// --from/--to dilengkapi saat mengetik lewat `unitconv __complete-units`, sehingga satuan
+// dari berkas pengguna dan alias semua bahasa ikut muncul.
+use crate::models::UnitRegistry;
+use crate::saran;
+use clap_complete::Shell;
+use std::io::Write;
+
+const NAMA_PROGRAM: &str = "unitconv";
+
+// Bash: opsi --from/--to ditangani lebih dulu, sisanya diteruskan ke fungsi buatan clap
+const KAIT_BASH: &str = r#"
+_unitconv_satuan() {
+    local cur="${COMP_WORDS[COMP_CWORD]}" prev="${COMP_WORDS[COMP_CWORD-1]}"
+    case "${prev}" in
+        --from|--to)
+            local asal=() i
+            for ((i = 1; i < COMP_CWORD - 1; i++)); do
+                [[ "${COMP_WORDS[i]}" == "--from" ]] && asal=(--from "${COMP_WORDS[i+1]}")
+            done
+            local IFS=$'\n'
+            COMPREPLY=($(unitconv __complete-units "${asal[@]}" -- "${cur}" 2>/dev/null))
+            return 0
+            ;;
+    esac
+    _unitconv "$@"
+}
+
+complete -F _unitconv_satuan -o bashdefault -o default unitconv
+"#;
+
+// Zsh: spesifikasi _arguments buatan clap untuk nilai SATUAN diarahkan ke fungsi ini
+const KAIT_ZSH: &str = r#"
+_unitconv_satuan() {
+    local -a asal satuan
+    local i
+    for ((i = 2; i < CURRENT - 1; i++)); do
+        [[ "${words[i]}" == "--from" ]] && asal=(--from "${words[i+1]}")
+    done
+    satuan=(${(f)"$(unitconv __complete-units "${asal[@]}" 2>/dev/null)"})
+    compadd -a satuan
+}
+"#;
+
+const KAIT_FISH: &str = r#"
+function __unitconv_satuan
+    set -l argumen (commandline -opc)
+    set -l asal
+    for i in (seq (math (count $argumen) - 1))
+        if test "$argumen[$i]" = --from
+            set asal --from $argumen[(math $i + 1)]
+        end
+    end
+    unitconv __complete-units $asal 2>/dev/null
+end
+"#;
+
+pub fn cetak_skrip(shell: Shell, mut perintah: clap::Command, keluaran: &mut impl Write) -> std::io::Result<()> {
+    let mut skrip = Vec::new();
+    clap_complete::generate(shell, &mut perintah, NAMA_PROGRAM, &mut skrip);
+    let skrip = String::from_utf8_lossy(&skrip);
+
+    match shell {
+        Shell::Bash => write!(keluaran, "{}{}", skrip, KAIT_BASH),
+        // Fungsi pembantu harus terdefinisi sebelum skrip clap memanggil compdef
+        Shell::Zsh => {
+            let skrip = skrip.replace(":SATUAN:_default", ":SATUAN:_unitconv_satuan");
+            let (kepala, isi) = skrip.split_once('\n').unwrap_or(("", &skrip));
+            write!(keluaran, "{}\n{}{}", kepala, KAIT_ZSH, isi)
+        }
+        Shell::Fish => {
+            write!(keluaran, "{}", KAIT_FISH)?;
+            for baris in skrip.lines() {
+                if baris.contains(" -l from ") || baris.contains(" -l to ") {
+                    writeln!(keluaran, "{} -f -a '(__unitconv_satuan)'", baris)?;
+                } else {
+                    writeln!(keluaran, "{}", baris)?;
+                }
+            }
+            Ok(())
+        }
+        // Shell lain hanya mendapat pelengkapan statis
+        _ => write!(keluaran, "{}", skrip),
+    }
+}
+
+// Ejaan satuan yang diawali `awalan`. Dengan `asal`, hanya satuan yang dimensinya sama
+// (satu kategori) yang didaftar; asal yang tidak dikenali tidak menyaring apa pun.
+pub fn daftar_satuan(asal: Option<&str>, awalan: &str) -> Vec<String> {
+    let registry = UnitRegistry::global();
+    let dimensi = asal.and_then(|asal| registry.urai(asal).ok()).map(|satuan| satuan.dimensi());
+    let awalan = awalan.to_lowercase();
+
+    let mut hasil: Vec<String> = Vec::new();
+    for satuan in registry.semua().iter().filter(|s| dimensi.is_none_or(|d| s.dimensi() == d)) {
+        for ejaan in saran::ejaan_satuan(satuan) {
+            if ejaan.to_lowercase().starts_with(&awalan) && !hasil.iter().any(|h| h == ejaan) {
+                hasil.push(ejaan.to_string());
+            }
+        }
+    }
+    hasil
+}
@@ -0,0 +1,196 @@
+// Pembulatan angka untuk tampilan. Pembulatan dilakukan pada digit desimal (representasi
+// terpendek f64), sehingga 2.675 dengan 2 desimal half-up menjadi 2.68, bukan 2.67.
+use crate::lokal::Lokal;
+use crate::pesan;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ModePembulatan {
+    // Ke genap terdekat saat tepat di tengah (pembulatan bankir)
+    #[default]
+    HalfEven,
+    HalfUp,
+    Truncate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Presisi {
+    // Seluruh digit f64, perilaku lama
+    #[default]
+    Penuh,
+    Desimal(usize),
+    AngkaPenting(usize),
+    // Angka penting mengikuti nilai masukan ("98.6" -> 3)
+    Otomatis,
+}
+
+// Nilai --sig-figs: angka atau "auto"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AngkaPenting {
+    Tetap(usize),
+    Otomatis,
+}
+
+impl FromStr for AngkaPenting {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<AngkaPenting, String> {
+        if s.eq_ignore_ascii_case("auto") {
+            return Ok(AngkaPenting::Otomatis);
+        }
+        match s.parse::<usize>() {
+            Ok(0) | Err(_) => Err(pesan::format("opsi.angka_penting_tidak_valid", &[&s])),
+            Ok(n) => Ok(AngkaPenting::Tetap(n)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FormatAngka {
+    pub presisi: Presisi,
+    pub mode: ModePembulatan,
+    pub lokal: Lokal,
+}
+
+impl FormatAngka {
+    // Mode otomatis diselesaikan terhadap teks nilai masukan seperti yang diketik, sehingga
+    // nol di belakang ikut dihitung ("10.0" -> 3 angka penting)
+    pub fn untuk_teks(&self, teks_input: &str) -> FormatAngka {
+        match self.presisi {
+            Presisi::Otomatis => FormatAngka {
+                presisi: Presisi::AngkaPenting(hitung_angka_penting(teks_input)),
+                ..*self
+            },
+            _ => *self,
+        }
+    }
+
+    // Untuk nilai yang teks aslinya tidak tersedia lagi, misal dari riwayat
+    pub fn untuk_input(&self, nilai_input: f64) -> FormatAngka {
+        self.untuk_teks(&nilai_input.to_string())
+    }
+
+    pub fn format(&self, nilai: f64) -> String {
+        if !nilai.is_finite() {
+            return nilai.to_string();
+        }
+        match self.presisi {
+            Presisi::Penuh | Presisi::Otomatis => self.lokal.format(nilai),
+            Presisi::Desimal(n) => {
+                let angka = Digit::dari_f64(nilai);
+                // Digit di depan koma = eksponen + 1
+                let simpan = angka.eksponen + 1 + n as i32;
+                self.lokal.terapkan(&angka.bulatkan(simpan, self.mode).tulis(n))
+            }
+            Presisi::AngkaPenting(n) => {
+                let bulat = Digit::dari_f64(nilai).bulatkan(n as i32, self.mode);
+                let desimal = (n as i32 - 1 - bulat.eksponen).max(0) as usize;
+                self.lokal.terapkan(&bulat.tulis(desimal))
+            }
+        }
+    }
+}
+
+// Jumlah angka penting pada teks angka: "10" -> 2, "10.0" -> 3, "0.0045" -> 2, "1.50e3" -> 3.
+// Pemisah desimal dan ribuan lokal apa pun diabaikan karena hanya digit yang dihitung.
+pub fn hitung_angka_penting(teks: &str) -> usize {
+    let mantisa = teks.split(['e', 'E']).next().unwrap_or(teks);
+    let digit: String = mantisa.chars().filter(|c| c.is_ascii_digit()).collect();
+    let tanpa_nol_depan = digit.trim_start_matches('0');
+    tanpa_nol_depan.len().max(1)
+}
+
+// Nilai = ±0.d₁d₂d₃… × 10^(eksponen+1), yaitu d₁ berada di posisi 10^eksponen
+struct Digit {
+    negatif: bool,
+    digit: Vec<u8>,
+    eksponen: i32,
+}
+
+impl Digit {
+    fn dari_f64(nilai: f64) -> Digit {
+        let teks = format!("{:e}", nilai.abs());
+        let (mantisa, eksponen) = teks.split_once('e').unwrap_or((&teks, "0"));
+        let digit = mantisa.chars().filter_map(|c| c.to_digit(10)).map(|d| d as u8).collect();
+        Digit {
+            negatif: nilai.is_sign_negative() && nilai != 0.0,
+            digit,
+            eksponen: eksponen.parse().unwrap_or(0),
+        }
+    }
+
+    // Menyisakan `simpan` digit pertama (boleh <= 0) dengan mode pembulatan
+    fn bulatkan(self, simpan: i32, mode: ModePembulatan) -> Digit {
+        if simpan < 0 {
+            return Digit { negatif: self.negatif, digit: vec![0], eksponen: self.eksponen - simpan };
+        }
+        let simpan = simpan as usize;
+        if simpan >= self.digit.len() {
+            return self;
+        }
+
+        let (disimpan, dibuang) = self.digit.split_at(simpan);
+        let terakhir_ganjil = disimpan.last().is_some_and(|d| d % 2 == 1);
+        let sisa_bukan_nol = dibuang[1..].iter().any(|&d| d != 0);
+        let naik = match mode {
+            ModePembulatan::Truncate => false,
+            ModePembulatan::HalfUp => dibuang[0] >= 5,
+            ModePembulatan::HalfEven => {
+                dibuang[0] > 5 || (dibuang[0] == 5 && (sisa_bukan_nol || terakhir_ganjil))
+            }
+        };
+
+        let mut digit = disimpan.to_vec();
+        let mut eksponen = self.eksponen;
+        if naik {
+            let mut i = digit.len();
+            loop {
+                if i == 0 {
+                    // 9.99 -> 10.0
+                    digit.insert(0, 1);
+                    eksponen += 1;
+                    break;
+                }
+                i -= 1;
+                if digit[i] == 9 {
+                    digit[i] = 0;
+                } else {
+                    digit[i] += 1;
+                    break;
+                }
+            }
+        }
+        if digit.is_empty() {
+            digit.push(0);
+        }
+        Digit { negatif: self.negatif, digit, eksponen }
+    }
+
+    // Notasi biasa dengan tepat `desimal` digit di belakang koma
+    fn tulis(&self, desimal: usize) -> String {
+        let digit_di = |posisi: i32| -> u8 {
+            let indeks = self.eksponen - posisi;
+            if indeks < 0 {
+                0
+            } else {
+                self.digit.get(indeks as usize).copied().unwrap_or(0)
+            }
+        };
+
+        let mut bulat = String::new();
+        for posisi in (0..=self.eksponen.max(0)).rev() {
+            bulat.push((b'0' + digit_di(posisi)) as char);
+        }
+        let bulat = bulat.trim_start_matches('0');
+        let bulat = if bulat.is_empty() { "0" } else { bulat };
+
+        let pecahan: String = (1..=desimal as i32).map(|i| (b'0' + digit_di(-i)) as char).collect();
+        let nol = bulat == "0" && pecahan.chars().all(|c| c == '0');
+        let tanda = if self.negatif && !nol { "-" } else { "" };
+        if pecahan.is_empty() {
+            format!("{}{}", tanda, bulat)
+        } else {
+            format!("{}{}.{}", tanda, bulat, pecahan)
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// Katalog pesan untuk semua teks yang tampil ke pengguna. Tiap bahasa punya satu berkas TOML;
+// tabel bertingkat dibaca sebagai kunci bertitik ("galat.nilai_tidak_valid") dan argumen
+// ditulis {0}, {1}, ... sehingga urutannya boleh berbeda antarbahasa.
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+const KATALOG_ID: &str = include_str!("pesan_id.toml");
+const KATALOG_EN: &str = include_str!("pesan_en.toml");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, clap::ValueEnum)]
+pub enum Bahasa {
+    #[default]
+    Id,
+    En,
+}
+
+impl Bahasa {
+    pub fn semua() -> &'static [Bahasa] {
+        &[Bahasa::Id, Bahasa::En]
+    }
+
+    pub fn kode(&self) -> &'static str {
+        match self {
+            Bahasa::Id => "id",
+            Bahasa::En => "en",
+        }
+    }
+
+    fn sumber(&self) -> &'static str {
+        match self {
+            Bahasa::Id => KATALOG_ID,
+            Bahasa::En => KATALOG_EN,
+        }
+    }
+
+    // Urutan POSIX: LC_ALL, LC_MESSAGES, lalu LANG ("en_US.UTF-8" -> en)
+    pub fn dari_env() -> Bahasa {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|nama| std::env::var(nama).ok())
+            .find(|nilai| !nilai.is_empty())
+            .and_then(|nilai| Bahasa::dari_kode(&nilai))
+            .unwrap_or_default()
+    }
+
+    pub fn dari_kode(kode: &str) -> Option<Bahasa> {
+        let bahasa = kode.split(['_', '-', '.', '@']).next().unwrap_or("");
+        Bahasa::semua().iter().copied().find(|b| b.kode().eq_ignore_ascii_case(bahasa))
+    }
+}
+
+static BAHASA: OnceLock<Bahasa> = OnceLock::new();
+static KATALOG: OnceLock<HashMap<Bahasa, BTreeMap<String, String>>> = OnceLock::new();
+
+// Pasang bahasa sebelum pesan pertama dibuat; tanpa ini dipakai bahasa Indonesia
+pub fn pasang_bahasa(bahasa: Bahasa) -> Result<(), Bahasa> {
+    BAHASA.set(bahasa)
+}
+
+pub fn bahasa() -> Bahasa {
+    BAHASA.get().copied().unwrap_or_default()
+}
+
+pub fn katalog(bahasa: Bahasa) -> &'static BTreeMap<String, String> {
+    let semua = KATALOG.get_or_init(|| {
+        Bahasa::semua()
+            .iter()
+            .map(|&b| {
+                let tabel: toml::Table = toml::from_str(b.sumber())
+                    .unwrap_or_else(|e| panic!("Katalog pesan '{}' tidak valid: {}", b.kode(), e));
+                let mut isi = BTreeMap::new();
+                ratakan("", tabel, &mut isi);
+                (b, isi)
+            })
+            .collect()
+    });
+    &semua[&bahasa]
+}
+
+fn ratakan(awalan: &str, tabel: toml::Table, isi: &mut BTreeMap<String, String>) {
+    for (kunci, nilai) in tabel {
+        let kunci = if awalan.is_empty() { kunci } else { format!("{}.{}", awalan, kunci) };
+        match nilai {
+            toml::Value::Table(anak) => ratakan(&kunci, anak, isi),
+            toml::Value::String(teks) => {
+                isi.insert(kunci, teks);
+            }
+            lain => panic!("Pesan '{}' harus berupa teks, ditemukan {}", kunci, lain.type_str()),
+        }
+    }
+}
+
+// Teks dalam bahasa aktif; kunci yang belum diterjemahkan jatuh ke bahasa Indonesia
+pub fn coba(kunci: &str) -> Option<&'static str> {
+    katalog(bahasa())
+        .get(kunci)
+        .or_else(|| katalog(Bahasa::Id).get(kunci))
+        .map(String::as_str)
+}
+
+pub fn teks(kunci: &'static str) -> &'static str {
+    coba(kunci).unwrap_or(kunci)
+}
+
+// Teks dengan argumen: format("galat.baris", &[&nomor, &pesan])
+pub fn format(kunci: &'static str, argumen: &[&dyn Display]) -> String {
+    isi_argumen(teks(kunci), argumen)
+}
+
+fn isi_argumen(templat: &str, argumen: &[&dyn Display]) -> String {
+    let mut hasil = String::with_capacity(templat.len());
+    let mut sisa = templat;
+    while let Some(buka) = sisa.find('{') {
+        hasil.push_str(&sisa[..buka]);
+        let setelah = &sisa[buka + 1..];
+        let indeks = setelah
+            .find('}')
+            .and_then(|tutup| Some((tutup, setelah[..tutup].parse::<usize>().ok()?)))
+            .filter(|&(_, indeks)| indeks < argumen.len());
+        match indeks {
+            Some((tutup, indeks)) => {
+                hasil.push_str(&argumen[indeks].to_string());
+                sisa = &setelah[tutup + 1..];
+            }
+            None => {
+                hasil.push('{');
+                sisa = setelah;
+            }
+        }
+    }
+    hasil.push_str(sisa);
+    hasil
+}
+
+// Argumen yang dipakai templat, untuk memeriksa bahwa terjemahan memakai argumen yang sama
+pub fn argumen_templat(templat: &str) -> Vec<usize> {
+    let mut indeks: Vec<usize> = templat
+        .split('{')
+        .skip(1)
+        .filter_map(|bagian| bagian.split_once('}')?.0.parse().ok())
+        .collect();
+    indeks.sort_unstable();
+    indeks.dedup();
+    indeks
+}
+
+// Awalan baris galat di stderr, misal "Error: [KESALAHAN] ..."
+pub fn galat(pesan: impl Display) -> String {
+    format("umum.galat", &[&pesan])
+}
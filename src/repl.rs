@@ -0,0 +1,127 @@
+use crate::converter;
+use crate::history;
+use crate::kalkulator;
+use crate::keluaran;
+use crate::models::UnitRegistry;
+use crate::pembulatan::FormatAngka;
+use crate::pesan;
+use crate::saran;
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use std::collections::HashSet;
+
+// Hasil terakhir yang dapat dipakai kembali lewat "_"
+struct HasilTerakhir {
+    nilai: f64,
+    simbol: String,
+}
+
+pub fn jalankan(angka: FormatAngka) -> Result<(), String> {
+    let mut editor = DefaultEditor::new().map_err(|e| pesan::format("repl.gagal_mulai", &[&e]))?;
+    println!("{}", pesan::teks("repl.sambutan"));
+
+    let mut terakhir: Option<HasilTerakhir> = None;
+    // Catatan tafsiran cukup sekali per sesi
+    let mut sudah_dicatat = HashSet::new();
+    loop {
+        match editor.readline("unitconv> ") {
+            Ok(baris) => {
+                let baris = baris.trim();
+                if baris.is_empty() {
+                    continue;
+                }
+                let _ = editor.add_history_entry(baris);
+
+                match baris {
+                    ":q" | ":quit" | ":keluar" | "exit" | "quit" => break,
+                    ":list" | ":daftar" => keluaran::tampilkan_daftar(),
+                    ":history" | ":riwayat" => history::tampilkan_riwayat(angka),
+                    ":help" | ":bantuan" => tampilkan_bantuan(),
+                    _ if baris.starts_with(':') => {
+                        eprintln!("{}", pesan::galat(pesan::format("repl.perintah_tidak_dikenal", &[&baris])));
+                    }
+                    _ => match proses_baris(baris, terakhir.as_ref(), angka) {
+                        Ok((teks, hasil, catatan)) => {
+                            for teks in catatan.into_iter().filter(|teks| sudah_dicatat.insert(teks.clone())) {
+                                eprintln!("{}", pesan::format("umum.catatan", &[&teks]));
+                            }
+                            println!("{}", teks);
+                            terakhir = Some(hasil);
+                        }
+                        Err(pesan) => eprintln!("{}", pesan::galat(pesan)),
+                    },
+                }
+            }
+            // Ctrl-C membatalkan baris yang sedang diketik, Ctrl-D keluar
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(pesan::format("repl.gagal_baca", &[&e])),
+        }
+    }
+    Ok(())
+}
+
+fn tampilkan_bantuan() {
+    println!("{}", pesan::teks("repl.bantuan"));
+}
+
+fn proses_baris(baris: &str, terakhir: Option<&HasilTerakhir>, angka: FormatAngka) -> Result<(String, HasilTerakhir, Vec<String>), String> {
+    let baris = ganti_hasil_terakhir(baris, terakhir)?;
+    let (ekspresi, tujuan) = kalkulator::pisahkan_tujuan(&baris);
+    let registry = UnitRegistry::global();
+
+    // "10 kg to lbs": konversi biasa, dicatat ke riwayat seperti perintah convert
+    let nilai_satuan = kalkulator::pisahkan_teks_nilai(ekspresi)
+        .and_then(|(teks, dari)| Some((angka.lokal.urai_nilai(teks).ok()?, teks, dari)));
+    if let (Some(ke), Some((nilai, teks, dari))) = (tujuan, nilai_satuan) {
+        let satuan_tunggal = !dari.contains(char::is_whitespace) && !dari.contains(['+', '(', ')']);
+        if satuan_tunggal || registry.urai(dari).is_ok() {
+            return konversi(nilai, dari, ke, angka.untuk_teks(teks));
+        }
+    }
+
+    let hasil = kalkulator::evaluasi(registry, ekspresi, tujuan)?;
+    let teks = keluaran::format_evaluasi(ekspresi, &hasil, angka);
+    let simbol = hasil.satuan.map(|s| s.simbol().to_string()).unwrap_or_default();
+    Ok((teks, HasilTerakhir { nilai: hasil.nilai, simbol }, hasil.catatan))
+}
+
+fn konversi(nilai: f64, dari: &str, ke: &str, angka: FormatAngka) -> Result<(String, HasilTerakhir, Vec<String>), String> {
+    match converter::konversi_teks(nilai, dari, ke) {
+        Ok((asal, tujuan, hasil)) => {
+            history::simpan_riwayat(dari, ke, nilai, Some(hasil), None);
+            let teks = keluaran::format_konversi(nilai, &asal, hasil, &tujuan, angka);
+            let catatan = saran::catatan_konversi(UnitRegistry::global(), dari, ke);
+            Ok((teks, HasilTerakhir { nilai: hasil, simbol: tujuan.simbol().to_string() }, catatan))
+        }
+        Err(galat) => {
+            history::simpan_riwayat(dari, ke, nilai, None, Some(galat.to_string()));
+            Err(galat.to_string())
+        }
+    }
+}
+
+// Ganti "_" yang berdiri sendiri dengan hasil terakhir; "gal_us" tidak tersentuh
+fn ganti_hasil_terakhir(baris: &str, terakhir: Option<&HasilTerakhir>) -> Result<String, String> {
+    let karakter: Vec<char> = baris.chars().collect();
+    let bagian_nama = |c: Option<&char>| c.is_some_and(|c| c.is_alphanumeric() || *c == '_');
+
+    let mut hasil = String::new();
+    for (i, &c) in karakter.iter().enumerate() {
+        let berdiri_sendiri = c == '_'
+            && !bagian_nama(i.checked_sub(1).and_then(|j| karakter.get(j)))
+            && !bagian_nama(karakter.get(i + 1));
+        if !berdiri_sendiri {
+            hasil.push(c);
+            continue;
+        }
+
+        let terakhir = terakhir.ok_or_else(|| pesan::teks("repl.tanpa_hasil"))?;
+        if terakhir.simbol.is_empty() {
+            hasil.push_str(&terakhir.nilai.to_string());
+        } else {
+            hasil.push_str(&format!("({} {})", terakhir.nilai, terakhir.simbol));
+        }
+    }
+    Ok(hasil)
+}
@@ -0,0 +1,147 @@
+use crate::awalan::{self, BentukAwalan};
+use crate::converter::KonversiError;
+use crate::ekspresi::GalatSatuan;
+use crate::models::{Satuan, UnitRegistry};
+use crate::pesan;
+use std::cmp::min;
+
+// Implementasi sederhana jarak Levenshtein
+pub fn hitung_jarak_levenshtein(s1: &str, s2: &str) -> usize {
+    let panjang1 = s1.chars().count();
+    let panjang2 = s2.chars().count();
+    let mut matriks = vec![vec![0; panjang2 + 1]; panjang1 + 1];
+
+    for (i, baris) in matriks.iter_mut().enumerate() { baris[0] = i; }
+    for (j, sel) in matriks[0].iter_mut().enumerate() { *sel = j; }
+
+    for (i, c1) in s1.chars().enumerate() {
+        for (j, c2) in s2.chars().enumerate() {
+            let biaya = if c1 == c2 { 0 } else { 1 };
+            matriks[i + 1][j + 1] = min(
+                min(matriks[i][j + 1] + 1, matriks[i + 1][j] + 1),
+                matriks[i][j] + biaya
+            );
+        }
+    }
+    matriks[panjang1][panjang2]
+}
+
+pub fn saran_satuan(input: &str) -> Option<String> {
+    saran_terbaik(input, |_| true)
+}
+
+// Karakter yang membuat ejaan menjadi ekspresi ("in^2", "km/h", "m²"); ejaan seperti itu, juga
+// pangkat tanpa tanda ("in2"), tidak disarankan untuk satu bagian ekspresi agar pangkat atau
+// operatornya tidak berganda
+const KARAKTER_EKSPRESI: &[char] = &['/', '*', '·', '×', '^', '(', ')', '⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹', '⁻'];
+
+fn saran_terbaik(input: &str, boleh: impl Fn(&str) -> bool) -> Option<String> {
+    let registry = UnitRegistry::global();
+    let input_kecil = input.to_lowercase();
+    let mut cocok_terbaik = None;
+    let mut jarak_min = usize::MAX;
+
+    // Nama, simbol (misal "kg", "cm") dan alias dari semua bahasa serta berkas pengguna;
+    // yang disarankan adalah ejaan yang paling mirip, bukan selalu nama tampilan
+    for satuan in registry.semua() {
+        for ejaan in ejaan_satuan(satuan).filter(|e| boleh(e)) {
+            let jarak = hitung_jarak_levenshtein(&input_kecil, &ejaan.replace('°', "").to_lowercase());
+            if jarak < jarak_min {
+                jarak_min = jarak;
+                cocok_terbaik = Some(ejaan.to_string());
+            }
+        }
+    }
+
+    // Bentuk berawalan: "kilometr" -> "kilometer", "mgg" -> "mg"
+    for (awalan, bentuk, sisa) in awalan::pisahkan(input) {
+        let sisa_kecil = sisa.to_lowercase();
+        for satuan in registry.semua().iter().filter(|s| s.awalan().menerima(awalan)) {
+            let (depan, ejaan_dasar): (&str, Vec<&str>) = match bentuk {
+                BentukAwalan::Simbol => (awalan.simbol_utama(), vec![satuan.simbol()]),
+                BentukAwalan::Nama => (awalan.nama_tampilan(), ejaan_satuan(satuan).filter(|e| *e != satuan.simbol()).collect()),
+            };
+            for ejaan in ejaan_dasar.into_iter().filter(|e| boleh(e)) {
+                let jarak = hitung_jarak_levenshtein(&sisa_kecil, &ejaan.to_lowercase());
+                if jarak < jarak_min {
+                    jarak_min = jarak;
+                    cocok_terbaik = Some(format!("{}{}", depan, ejaan));
+                }
+            }
+        }
+    }
+
+    // Sarankan jika jarak kecil relatif terhadap panjang string
+    if jarak_min <= 3 {
+        cocok_terbaik
+    } else {
+        None
+    }
+}
+
+// Ejaan satu kata yang bisa disarankan; nama berspasi ("mil per jam") dilewati
+pub(crate) fn ejaan_satuan(satuan: &Satuan) -> impl Iterator<Item = &str> {
+    std::iter::once(satuan.nama())
+        .chain(std::iter::once(satuan.simbol()))
+        .chain(satuan.alias().iter().map(String::as_str))
+        .filter(|ejaan| !ejaan.contains(char::is_whitespace))
+}
+
+// Satuan tunggal atau ekspresi; pesan error menyertakan saran untuk bagian yang salah eja
+pub fn urai_dengan_saran(peran: &'static str, input: &str) -> Result<Satuan, KonversiError> {
+    let registry = UnitRegistry::global();
+    registry.urai(input).map_err(|galat| match galat {
+        GalatSatuan::TidakDikenali(bagian) => KonversiError::SatuanTidakDikenali {
+            peran,
+            input: input.to_string(),
+            saran: if bagian == input.trim() { saran_satuan(&bagian) } else { saran_bagian(registry, input, &bagian) },
+        },
+        GalatSatuan::Sintaks(detail) => KonversiError::SatuanTidakValid {
+            peran,
+            input: input.to_string(),
+            detail,
+        },
+    })
+}
+
+// Saran untuk satu bagian ekspresi ("psi*in^2"): hanya ejaan tunggal yang menggantikan bagian
+// itu utuh, dan hanya jika ekspresi hasilnya memang dapat diurai
+fn saran_bagian(registry: &UnitRegistry, input: &str, bagian: &str) -> Option<String> {
+    let tunggal = |ejaan: &str| !ejaan.contains(KARAKTER_EKSPRESI) && !ejaan.ends_with(|c: char| c.is_ascii_digit());
+    let saran = saran_terbaik(bagian, tunggal)?;
+    let bagian_nama = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let (posisi, _) = input.match_indices(bagian).find(|&(posisi, _)| {
+        !bagian_nama(input[..posisi].chars().next_back()) && !bagian_nama(input[posisi + bagian.len()..].chars().next())
+    })?;
+    let kandidat = format!("{}{}{}", &input[..posisi], saran, &input[posisi + bagian.len()..]);
+    registry.urai(&kandidat).is_ok().then_some(kandidat)
+}
+
+// Catatan saat ejaan ambigu ("gal", "pint") diartikan menurut sistem bawaan; pemanggil
+// yang memutuskan mencetaknya ke stderr atau menyertakannya di JSON
+pub fn catatan_tafsiran(registry: &UnitRegistry, input: &str) -> Vec<String> {
+    let mut catatan = Vec::new();
+    for bagian in input.split(['*', '·', '×', '/', '(', ')', '^']).map(str::trim) {
+        let Some((pilihan, lain)) = registry.tafsiran_ambigu(bagian) else { continue };
+        let sistem_lain = lain.sistem().map_or("", |s| s.kode());
+        let teks = pesan::format(
+            "umum.tafsiran_ambigu",
+            &[&bagian, &pilihan.nama(), &registry.ejaan_tegas(pilihan), &lain.nama(), &registry.ejaan_tegas(lain), &sistem_lain],
+        );
+        if !catatan.contains(&teks) {
+            catatan.push(teks);
+        }
+    }
+    catatan
+}
+
+// Catatan untuk sepasang satuan asal dan tujuan, tanpa duplikat
+pub fn catatan_konversi(registry: &UnitRegistry, dari: &str, ke: &str) -> Vec<String> {
+    let mut catatan = catatan_tafsiran(registry, dari);
+    for teks in catatan_tafsiran(registry, ke) {
+        if !catatan.contains(&teks) {
+            catatan.push(teks);
+        }
+    }
+    catatan
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use common::konversi;
+use minirustcli::models::UnitRegistry;
+
+// KB, MB dan GB tetap 1024ⁿ seperti sejak awal (konvensi JEDEC); awalan SI pada byte ("kB", "TB")
+// desimal, awalan biner ("KiB") kelipatan 1024 (IEC 80000-13)
+#[test]
+fn satuan_data_lama_dan_awalan() {
+    assert_eq!(konversi(1.0, "KB", "B"), 1024.0);
+    assert_eq!(konversi(1.0, "MB", "B"), 1048576.0);
+    assert_eq!(konversi(1.0, "GB", "MB"), 1024.0);
+    assert_eq!(konversi(1.0, "kb", "B"), 1024.0);
+    assert_eq!(konversi(1.0, "kB", "B"), 1000.0);
+    assert_eq!(konversi(1.0, "TB", "B"), 1e12);
+    assert_eq!(konversi(1.0, "PB", "TB"), 1000.0);
+    assert_eq!(konversi(1.0, "KiB", "KB"), 1.0);
+    assert_eq!(konversi(1.0, "KiB", "B"), 1024.0);
+    assert_eq!(konversi(1.0, "GiB", "MiB"), 1024.0);
+    assert_eq!(konversi(1.0, "kibibyte", "B"), 1024.0);
+}
+
+#[test]
+fn byte_hanya_menerima_awalan_kelipatan() {
+    let registry = UnitRegistry::bawaan();
+    assert!(registry.urai("hB").is_err());
+    assert!(registry.urai("µB").is_err());
+    // "mB" tidak pernah berarti milibita
+    assert_ne!(registry.urai("mB").map(|s| s.faktor()), Ok(0.001));
+}
+
+#[test]
+fn simbol_berawalan_tidak_bertabrakan() {
+    let registry = UnitRegistry::bawaan();
+    let mb = registry.urai("Mb").unwrap();
+    assert_eq!(mb.simbol(), "MB");
+    assert_eq!(mb.faktor(), registry.urai("MB").unwrap().faktor());
+
+    assert_eq!(registry.urai("milligram").unwrap().simbol(), "mg");
+    assert_eq!(konversi(1.0, "milligram", "g"), 0.001);
+}
@@ -0,0 +1,14 @@
+use minirustcli::batch;
+use minirustcli::pembulatan::FormatAngka;
+
+// Satu baris yang bukan UTF-8 hanya menggagalkan baris itu; baris berikutnya tetap dikonversi
+#[test]
+fn baris_bukan_utf8_gagal_sendiri() {
+    let berkas = std::env::temp_dir().join(format!("unitconv-batch-{}.txt", std::process::id()));
+    std::fs::write(&berkas, b"x\xff\n1 km m\r\n# komentar\n\n2 km m").unwrap();
+
+    let ringkasan = batch::jalankan(Some(&berkas), None, false, FormatAngka::default()).unwrap();
+    assert_eq!((ringkasan.berhasil, ringkasan.gagal), (2, 1));
+
+    std::fs::remove_file(&berkas).unwrap();
+}
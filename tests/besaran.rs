@@ -0,0 +1,72 @@
+use minirustcli::besaran::*;
+use minirustcli::models::{Kuantitas, UnitRegistry};
+
+// Faktor tetap dibaca build.rs dari satuan_bawaan.json; kategori dan awalannya harus cocok dengan registry
+#[test]
+fn faktor_tetap_sama_dengan_tabel_bawaan() {
+    let registry = UnitRegistry::bawaan();
+    for info in SEMUA_SATUAN {
+        let satuan = registry
+            .urai(info.simbol)
+            .unwrap_or_else(|_| panic!("'{}' tidak ada di tabel bawaan", info.simbol));
+        assert_eq!(satuan.kategori(), Some(info.kategori), "kategori '{}'", info.simbol);
+        for nilai in [0.0, 1.0, 123.5] {
+            let tetap = (nilai + info.offset) * info.pembilang / info.penyebut;
+            let runtime = satuan.ke_dasar(nilai);
+            let selisih = (tetap - runtime).abs() / runtime.abs().max(f64::MIN_POSITIVE);
+            assert!(selisih < 1e-12, "'{}': {} {} != {}", info.simbol, nilai, tetap, runtime);
+        }
+    }
+}
+
+#[test]
+fn simbol_tetap_unik() {
+    for (i, info) in SEMUA_SATUAN.iter().enumerate() {
+        assert!(
+            SEMUA_SATUAN[..i].iter().all(|lain| lain.simbol != info.simbol),
+            "simbol '{}' dipakai dua kali",
+            info.simbol
+        );
+    }
+}
+
+fn hampir(hasil: f64, acuan: f64) {
+    assert!((hasil - acuan).abs() <= acuan.abs() * 1e-12, "{} berbeda dari acuan {}", hasil, acuan);
+}
+
+#[test]
+fn konversi_sekategori() {
+    let jarak: Length<Km> = Length::new(5.0);
+    hampir(jarak.ke::<M>().nilai(), 5000.0);
+    hampir(jarak.ke::<Mile>().nilai(), 5.0 / 1.609344);
+    hampir(Mass::<Lb>::new(1.0).ke::<G>().nilai(), 453.59237);
+    hampir(Volume::<Gal>::new(1.0).ke::<L>().nilai(), 3.785411784);
+    hampir(Temperature::<Fahrenheit>::new(98.6).ke::<Celsius>().nilai(), 37.0);
+    hampir(Temperature::<Celsius>::new(0.0).ke::<Kelvin>().nilai(), 273.15);
+    hampir(Speed::<Kmh>::new(36.0).ke::<Mps>().nilai(), 10.0);
+    hampir(Data::<Gib>::new(1.0).ke::<Mb>().nilai(), 1024.0);
+    hampir(Data::<Kb>::new(1.0).ke::<B>().nilai(), 1024.0);
+}
+
+#[test]
+fn aritmetika_dan_perbandingan() {
+    let total = Length::<Km>::new(5.0) + Length::<M>::new(250.0);
+    hampir(total.nilai(), 5.25);
+    hampir((Length::<M>::new(2.0) - Length::<Cm>::new(50.0)).nilai(), 1.5);
+    hampir((Length::<M>::new(3.0) * Length::<Cm>::new(200.0)).nilai(), 6.0);
+    hampir((Length::<Km>::new(36.0) / Time::<H>::new(1.0)).nilai(), 10.0);
+    assert!(Length::<Km>::new(1.0) > Length::<M>::new(999.0));
+    assert!(Mass::<Kg>::new(1.0) == Mass::<G>::new(1000.0));
+}
+
+#[test]
+fn jembatan_ke_kuantitas_runtime() {
+    let kuantitas = Length::<Km>::new(2.0).ke_kuantitas().unwrap();
+    assert_eq!(kuantitas.satuan.simbol(), "km");
+
+    let meter: Length<M> = Length::try_from(kuantitas).unwrap();
+    hampir(meter.nilai(), 2000.0);
+
+    let berat = Kuantitas::new(1.0, UnitRegistry::global().urai("kg").unwrap());
+    assert!(Length::<M>::try_from(berat).is_err());
+}
@@ -0,0 +1,21 @@
+// Helper bersama tes integrasi; tidak setiap berkas tes memakai semuanya
+#![allow(dead_code)]
+
+use minirustcli::converter;
+use minirustcli::models::UnitRegistry;
+
+pub fn konversi_di(registry: &UnitRegistry, nilai: f64, dari: &str, ke: &str) -> f64 {
+    let dari = registry.urai(dari).unwrap();
+    let ke = registry.urai(ke).unwrap();
+    converter::konversi(nilai, &dari, &ke).unwrap()
+}
+
+pub fn konversi(nilai: f64, dari: &str, ke: &str) -> f64 {
+    konversi_di(&UnitRegistry::bawaan(), nilai, dari, ke)
+}
+
+// Tabel acuan (NIST SP 811 dan sejenisnya) dicetak dengan 6-7 angka penting
+pub fn hampir(hasil: f64, acuan: f64) {
+    let selisih = ((hasil - acuan) / acuan).abs();
+    assert!(selisih < 5e-7, "{} berbeda dari acuan {}", hasil, acuan);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "big-decimal")]
+
+use minirustcli::converter::KonversiError;
+use minirustcli::desimal;
+
+fn konversi(nilai: &str, dari: &str, ke: &str) -> String {
+    let (_, _, _, hasil) = desimal::konversi_teks(nilai, dari, ke).unwrap();
+    desimal::format(&hasil)
+}
+
+#[test]
+fn petabyte_ke_byte_persis() {
+    assert_eq!(konversi("1", "PB", "B"), "1000000000000000");
+    assert_eq!(konversi("1", "PiB", "B"), "1125899906842624");
+    assert_eq!(konversi("3", "EB", "kB"), "3000000000000000");
+}
+
+#[test]
+fn nilai_tidak_melewati_f64() {
+    // 2^53 + 1 tidak dapat diwakili f64
+    assert_eq!(konversi("9007199254740993", "B", "B"), "9007199254740993");
+    assert_eq!(konversi("9007199254740993", "m", "mm"), "9007199254740993000");
+    assert_eq!(
+        konversi("123456789012345678901234567890.123456789", "nm", "km"),
+        "123456789012345678.901234567890123456789"
+    );
+}
+
+#[test]
+fn nilai_sangat_kecil() {
+    assert_eq!(konversi("1e-30", "m", "nm"), "0.000000000000000000001");
+    assert_eq!(konversi("0.1", "m", "mm"), "100");
+}
+
+#[test]
+fn faktor_desimal_persis() {
+    assert_eq!(konversi("12", "inch", "m"), "0.3048");
+    assert_eq!(konversi("1", "lbs", "g"), "453.59237");
+    assert_eq!(konversi("1", "miles", "inch"), "63360");
+    assert_eq!(konversi("1", "gal", "ml"), "3785.411784");
+}
+
+#[test]
+fn suhu_dengan_offset() {
+    assert_eq!(konversi("98.6", "°F", "°C"), "37");
+    assert_eq!(konversi("-40", "°F", "°C"), "-40");
+    assert_eq!(konversi("0", "K", "°F"), "-459.67");
+    assert_eq!(konversi("100", "°F", "°C"), "37.777777777777777777777777777777777777777777777778");
+}
+
+#[test]
+fn galat_tetap_dilaporkan() {
+    assert!(matches!(
+        desimal::konversi_teks("-500", "°C", "K"),
+        Err(KonversiError::DiBawahNolMutlak { .. })
+    ));
+    assert!(matches!(
+        desimal::konversi_teks("1", "kg", "m"),
+        Err(KonversiError::DimensiBerbeda { .. })
+    ));
+    assert!(matches!(desimal::urai_nilai("NaN"), Err(KonversiError::NilaiTidakHingga)));
+    assert!(matches!(desimal::urai_nilai("1,5"), Err(KonversiError::NilaiTidakValid(_))));
+}
+
+// Offset tekanan ukur ikut sebagai pecahan persis, bukan f64
+#[test]
+fn tekanan_ukur_persis() {
+    assert_eq!(konversi("0", "psig", "Pa"), "101325");
+    assert_eq!(konversi("101325", "Pa", "psig"), "0");
+    assert_eq!(konversi("1.5", "barg", "Pa"), "251325");
+}
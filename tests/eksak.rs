@@ -0,0 +1,66 @@
+use minirustcli::converter::KonversiError;
+use minirustcli::eksak;
+use minirustcli::models::UnitRegistry;
+use minirustcli::saran;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+
+fn konversi(nilai: &str, dari: &str, ke: &str) -> String {
+    let registry = UnitRegistry::bawaan();
+    let dari = registry.urai(dari).unwrap();
+    let ke = registry.urai(ke).unwrap();
+    let nilai = eksak::dari_teks(nilai).unwrap();
+    eksak::format(&eksak::konversi(&nilai, &dari, &ke).unwrap(), None)
+}
+
+#[test]
+fn faktor_tabel_dibaca_persis() {
+    assert_eq!(konversi("1", "inch", "m"), "0.0254");
+    assert_eq!(konversi("1", "oz", "kg"), "0.028349523125");
+    assert_eq!(konversi("1", "hp", "W"), "745.69987158227022");
+    assert_eq!(konversi("1", "eV", "J"), "0.0000000000000000001602176634");
+    assert_eq!(konversi("1", "°F", "K"), "46067/180");
+}
+
+// Hasil kali faktor melewati 2^53 dan tidak lagi dibulatkan f64 sebelum dijadikan pecahan
+#[test]
+fn hasil_kali_faktor_tetap_persis() {
+    assert_eq!(konversi("1", "psi*inch^2", "N"), "4.4482216152605");
+
+    let psi = BigRational::new(BigInt::from(44482216152605_u64), BigInt::from(6451600000_u64));
+    let psi_kuadrat = eksak::format(&(&psi * &psi), None);
+    assert_eq!(konversi("1", "psi^2", "Pa^2"), psi_kuadrat);
+    assert_eq!(konversi("1", "mi^3", "m^3"), "4168181825.440579584");
+}
+
+#[test]
+fn desimal_tertulis_tanpa_f64() {
+    let persis = |teks: &str| eksak::dari_teks(teks).map(|n| eksak::format(&n, None));
+    assert_eq!(persis("0.1").as_deref(), Some("0.1"));
+    assert_eq!(persis("1e-6").as_deref(), Some("0.000001"));
+    assert_eq!(persis("-273.15").as_deref(), Some("-273.15"));
+    assert_eq!(persis("12345678901234567890.5").as_deref(), Some("12345678901234567890.5"));
+    assert_eq!(persis("abc"), None);
+    assert_eq!(persis("1.2.3"), None);
+}
+
+// Saran untuk satu bagian ekspresi tidak boleh mengarang ejaan gabungan seperti "psi*inch²^2"
+#[test]
+fn saran_bagian_ekspresi_tetap_dapat_diurai() {
+    for input in ["psi*inc^2", "kgg*m/s^2", "kmm/h"] {
+        match saran::urai_dengan_saran("asal", input) {
+            Err(KonversiError::SatuanTidakDikenali { saran: Some(saran), .. }) => {
+                assert!(!saran.contains(['²', '³']), "'{}' -> '{}'", input, saran);
+                assert!(UnitRegistry::global().urai(&saran).is_ok(), "'{}' -> '{}'", input, saran);
+            }
+            Err(KonversiError::SatuanTidakDikenali { saran: None, .. }) => {}
+            hasil => panic!("'{}': {:?}", input, hasil.map(|s| s.simbol().to_string())),
+        }
+    }
+    let saran = |input| match saran::urai_dengan_saran("asal", input) {
+        Err(KonversiError::SatuanTidakDikenali { saran, .. }) => saran,
+        _ => None,
+    };
+    assert_eq!(saran("kgg*m/s^2").as_deref(), Some("kg*m/s^2"));
+    assert_eq!(saran("kmm/h").as_deref(), Some("km/h"));
+}
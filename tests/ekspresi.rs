@@ -0,0 +1,25 @@
+use minirustcli::dimensi::Dimensi;
+use minirustcli::ekspresi::GalatSatuan;
+use minirustcli::models::UnitRegistry;
+
+#[test]
+fn pangkat_dimensi_di_luar_rentang_menjadi_galat() {
+    let registry = UnitRegistry::bawaan();
+    for input in ["(m^100)^2", "m^100*m^100", "m^-128/m", "(s^-128)^-1"] {
+        assert!(
+            matches!(registry.urai(input), Err(GalatSatuan::Sintaks(_))),
+            "'{}' seharusnya ditolak",
+            input
+        );
+    }
+    assert_eq!(registry.urai("m^127").unwrap().dimensi(), Dimensi([127, 0, 0, 0, 0, 0, 0, 0]));
+    assert_eq!(Dimensi::dari_str("L^100 L^100"), None);
+}
+
+#[test]
+fn aritmetika_dimensi_tercek() {
+    assert_eq!(Dimensi::PANJANG.pangkat(127), Some(Dimensi([127, 0, 0, 0, 0, 0, 0, 0])));
+    assert_eq!(Dimensi::PANJANG.pangkat(127).unwrap().kali(Dimensi::PANJANG), None);
+    assert_eq!(Dimensi([-128, 0, 0, 0, 0, 0, 0, 0]).pangkat(-1), None);
+    assert_eq!(Dimensi::MASSA.bagi(Dimensi::WAKTU), Some(Dimensi([0, 1, -1, 0, 0, 0, 0, 0])));
+}
@@ -0,0 +1,30 @@
+mod common;
+
+use common::{hampir, konversi};
+
+// NIST SP 811 lampiran B.8, faktor ke joule
+#[test]
+fn faktor_ke_joule() {
+    // Kalori termokimia
+    hampir(konversi(1.0, "cal", "J"), 4.184);
+    hampir(konversi(1.0, "Wh", "J"), 3.6e3);
+    // British thermal unit (International Table)
+    hampir(konversi(1.0, "BTU", "J"), 1.055056e3);
+    // Therm (U.S.)
+    hampir(konversi(1.0, "therm", "J"), 1.054804e8);
+    // Nilai persis sejak redefinisi SI 2019 (CODATA 2018)
+    hampir(konversi(1.0, "eV", "J"), 1.602176634e-19);
+}
+
+// NIST SP 811 lampiran B.8, faktor ke watt
+#[test]
+fn faktor_ke_watt() {
+    // Horsepower 550 ft·lbf/s
+    hampir(konversi(1.0, "hp", "W"), 7.456999e2);
+    // Horsepower metrik
+    hampir(konversi(1.0, "PS", "W"), 7.354988e2);
+    hampir(konversi(1.0, "BTU/h", "W"), 2.930711e-1);
+    // Ton refrigerasi = 12 000 BTU(IT)/h
+    hampir(konversi(1.0, "TR", "W"), 3.516853e3);
+    hampir(konversi(1.0, "TR", "BTU/h"), 12000.0);
+}
@@ -0,0 +1,117 @@
+use minirustcli::kalkulator::{self, HasilEvaluasi};
+use minirustcli::keluaran;
+use minirustcli::lokal::Lokal;
+use minirustcli::models::UnitRegistry;
+use minirustcli::pembulatan::{FormatAngka, Presisi};
+use minirustcli::pesan;
+
+fn evaluasi(ekspresi: &str) -> Result<HasilEvaluasi, String> {
+    let (ekspresi, tujuan) = kalkulator::pisahkan_tujuan(ekspresi);
+    kalkulator::evaluasi(&UnitRegistry::bawaan(), ekspresi, tujuan)
+}
+
+// Nilai dan simbol satuan hasil; kosong untuk hasil tanpa dimensi
+fn hasil(ekspresi: &str) -> (f64, String) {
+    let hasil = evaluasi(ekspresi).unwrap_or_else(|e| panic!("'{}' gagal: {}", ekspresi, e));
+    (hasil.nilai, hasil.satuan.map(|s| s.simbol().to_string()).unwrap_or_default())
+}
+
+fn hampir(ekspresi: &str, acuan: f64, simbol: &str) {
+    let (nilai, satuan) = hasil(ekspresi);
+    assert!((nilai - acuan).abs() <= acuan.abs() * 1e-12, "'{}' = {} berbeda dari acuan {}", ekspresi, nilai, acuan);
+    assert_eq!(satuan, simbol, "satuan hasil '{}'", ekspresi);
+}
+
+#[test]
+fn urutan_operasi() {
+    hampir("2 + 3 * 4", 14.0, "");
+    hampir("2 * 3 + 4", 10.0, "");
+    hampir("(2 + 3) * 4", 20.0, "");
+    hampir("10 - 4 - 3", 3.0, "");
+    hampir("100 / 10 / 2", 5.0, "");
+    hampir("2 * 3 ^ 2", 18.0, "");
+    // Minus uner lebih lemah dari pangkat, dan pangkat asosiatif kanan
+    hampir("-2 ^ 2", -4.0, "");
+    hampir("(-2) ^ 2", 4.0, "");
+    hampir("2 ^ 3 ^ 2", 512.0, "");
+    hampir("2 ^ -1", 0.5, "");
+    hampir("-2 ^ -2", -0.25, "");
+    hampir("2 * -3", -6.0, "");
+    assert!(evaluasi("1 / 0").is_err());
+    assert!(evaluasi("(1 + 2").is_err());
+}
+
+#[test]
+fn aritmetika_satuan() {
+    hampir("3 h 20 min + 40 min", 4.0, "h");
+    hampir("1 km + 500 m in m", 1500.0, "m");
+    hampir("120 km / 1.5 h in km/h", 80.0, "km/h");
+    hampir("2 m * 3 m in m²", 6.0, "m²");
+    hampir("(2 m)^2 in cm²", 40000.0, "cm²");
+    hampir("(1 + 2) km in m", 3000.0, "m");
+    hampir("10 kg * 9.80665 m / s^2 in N", 98.0665, "N");
+    hampir("2 km / 4 km", 0.5, "");
+    assert!(evaluasi("1 km + 1 kg").is_err());
+    assert!(evaluasi("1 km - 1 s").is_err());
+}
+
+#[test]
+fn satuan_tujuan() {
+    assert_eq!(kalkulator::pisahkan_tujuan("1 mi in km"), ("1 mi", Some("km")));
+    assert_eq!(kalkulator::pisahkan_tujuan("5 inch in cm"), ("5 inch", Some("cm")));
+    assert_eq!(kalkulator::pisahkan_tujuan("3 ft + 2 inch"), ("3 ft + 2 inch", None));
+    assert_eq!(kalkulator::pisahkan_tujuan("60 mph -> km/h"), ("60 mph", Some("km/h")));
+    hampir("5 inch in cm", 12.7, "cm");
+    // "in" tepat setelah angka adalah inci, bukan penanda tujuan
+    assert_eq!(kalkulator::pisahkan_tujuan("10 in cm"), ("10 in", Some("cm")));
+    assert_eq!(kalkulator::pisahkan_tujuan("10 in in cm"), ("10 in", Some("cm")));
+    assert_eq!(kalkulator::pisahkan_tujuan("3 ft 2 in in cm"), ("3 ft 2 in", Some("cm")));
+    assert_eq!(kalkulator::pisahkan_tujuan("10 in"), ("10 in", None));
+    assert_eq!(kalkulator::pisahkan_kueri("10 in cm"), Some(("10", "in", "cm")));
+    assert_eq!(kalkulator::pisahkan_kueri("10in cm"), Some(("10", "in", "cm")));
+    hampir("10 in cm", 25.4, "cm");
+    hampir("3 ft 2 in in cm", 96.52, "cm");
+    hampir("1 mi ke km", 1.609344, "km");
+    hampir("90 min dalam h", 1.5, "h");
+    // Dimensi tujuan harus sama dengan dimensi hasil
+    assert!(evaluasi("3 m in s").is_err());
+    assert!(evaluasi("3 m in xyz").is_err());
+}
+
+#[test]
+fn pangkat_berdimensi_diperiksa() {
+    hampir("(3 m)^3 in m³", 27.0, "m³");
+    assert!(evaluasi("m ^ 0.5").is_err());
+    assert!(evaluasi("2 ^ (1 m)").is_err());
+    // Eksponen di luar i8, atau masih muat tetapi dimensi hasilnya tidak
+    let terlalu_besar = pesan::teks("kalkulator.pangkat_terlalu_besar");
+    for ekspresi in ["m ^ 200", "(m ^ 100) ^ 2", "m ^ 100 * m ^ 100", "(1 / m ^ 100) / m ^ 100"] {
+        assert_eq!(evaluasi(ekspresi).err().as_deref(), Some(terlalu_besar), "'{}'", ekspresi);
+    }
+}
+
+// Suhu °F dan K adalah titik pada skala bergeser: hanya dapat dinegasikan dan dikonversi
+#[test]
+fn suhu_skala_bergeser() {
+    hampir("-40 °F in °C", -40.0, "°C");
+    hampir("-10 °C in °F", 14.0, "°F");
+    hampir("50 °F in K", 283.15, "K");
+    hampir("10 °C + 5 °C", 15.0, "°C");
+    let bergeser = pesan::teks("kalkulator.suhu_bergeser");
+    for ekspresi in ["50 °F + 10 °F", "0 K - 10 K", "10 °C + 5 K", "2 * 50 °F", "300 K / 2", "(10 °F)^2"] {
+        assert_eq!(evaluasi(ekspresi).err().as_deref(), Some(bergeser), "'{}'", ekspresi);
+    }
+}
+
+// Hasil eval ditulis dengan aturan --precision/--sig-figs dan --locale yang sama dengan convert
+#[test]
+fn format_hasil_evaluasi() {
+    let hasil = evaluasi("10 km / 3 h in km/h").unwrap();
+    let angka = FormatAngka { presisi: Presisi::Desimal(2), ..FormatAngka::default() };
+    assert_eq!(keluaran::format_evaluasi("10 km / 3 h", &hasil, angka), "10 km / 3 h = 3.33 km/h");
+
+    let hasil = evaluasi("1000 * 1.5").unwrap();
+    let angka = FormatAngka { presisi: Presisi::AngkaPenting(3), lokal: Lokal::ID_ID, ..FormatAngka::default() };
+    assert_eq!(keluaran::format_evaluasi("1000 * 1.5", &hasil, angka), "1000 * 1.5 = 1.500");
+    assert_eq!(keluaran::format_evaluasi("1000 * 1.5", &hasil, FormatAngka::default()), "1000 * 1.5 = 1500");
+}
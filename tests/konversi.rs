@@ -0,0 +1,199 @@
+mod common;
+
+use common::konversi;
+use minirustcli::converter::{self, KonversiError};
+use minirustcli::models::{Faktor, UnitRegistry};
+
+// Faktor tabel yang persis dalam desimal harus tercetak persis, tanpa ekor pembulatan
+#[test]
+fn faktor_persis_tidak_menumpuk_pembulatan() {
+    assert_eq!(konversi(1.0, "gal_us", "L").to_string(), "3.785411784");
+    assert_eq!(konversi(3.0, "gal_us", "L").to_string(), "11.356235352");
+    assert_eq!(konversi(1.0, "pt_us", "L").to_string(), "0.473176473");
+    assert_eq!(konversi(1.0, "floz_us", "ml").to_string(), "29.5735295625");
+    assert_eq!(konversi(1.0, "m/s", "km/h").to_string(), "3.6");
+    assert_eq!(konversi(1.0, "gal_us", "ml").to_string(), "3785.411784");
+}
+
+#[test]
+fn pecahan_disederhanakan() {
+    let faktor = Faktor::dari_teks("3785411784000/1e12").unwrap();
+    assert_eq!((faktor.pembilang(), faktor.penyebut()), (473176473.0, 125000000.0));
+    let faktor = Faktor::dari_teks("0.5/3").unwrap();
+    assert_eq!((faktor.pembilang(), faktor.penyebut()), (1.0, 6.0));
+    assert_eq!(Faktor::dari_teks("1/0"), None);
+    assert_eq!(Faktor::dari_teks("1.2.3"), None);
+}
+
+// Hasil rumus per kategori di converter.rs sebelum tabel satuan dipindah ke satuan_bawaan.json
+// (nilai, asal, tujuan, hasil lama). Makna yang sengaja berubah diuji di makna_awal_yang_berubah.
+const HASIL_AWAL: &[(f64, &str, &str, f64)] = &[
+    (1.0, "cm", "inch", 0.3937007874015748),
+    (1.0, "cm", "km", 1e-05),
+    (1.0, "cm", "miles", 6.213711922373339e-06),
+    (1.0, "inch", "cm", 2.54),
+    (1.0, "inch", "km", 2.5399999999999997e-05),
+    (1.0, "inch", "miles", 1.5782828282828283e-05),
+    (1.0, "km", "cm", 100000.0),
+    (1.0, "km", "inch", 39370.078740157485),
+    (1.0, "km", "miles", 0.621371192237334),
+    (1.0, "miles", "cm", 160934.4),
+    (1.0, "miles", "inch", 63360.00000000001),
+    (1.0, "miles", "km", 1.609344),
+    (1.0, "kg", "gram", 1000.0),
+    (1.0, "kg", "lbs", 2.2046226218487757),
+    (1.0, "gram", "kg", 0.001),
+    (1.0, "gram", "lbs", 0.002204622621848776),
+    (1.0, "lbs", "kg", 0.45359237),
+    (1.0, "lbs", "gram", 453.59237),
+    (1.0, "liter", "gallon", 0.26417205235814845),
+    (1.0, "liter", "ml", 1000.0),
+    (1.0, "gallon", "liter", 3.785411784),
+    (1.0, "gallon", "ml", 3785.411784),
+    (1.0, "ml", "liter", 0.001),
+    (1.0, "ml", "gallon", 0.00026417205235814843),
+    (1.0, "s", "min", 0.016666666666666666),
+    (1.0, "s", "h", 0.0002777777777777778),
+    (1.0, "min", "s", 60.0),
+    (1.0, "min", "h", 0.016666666666666666),
+    (1.0, "h", "s", 3600.0),
+    (1.0, "h", "min", 60.0),
+    (1.0, "m/s", "km/h", 3.6),
+    (1.0, "m/s", "mph", 2.2369362920544025),
+    (1.0, "km/h", "m/s", 0.2777777777777778),
+    (1.0, "km/h", "mph", 0.6213711922373341),
+    (1.0, "mph", "m/s", 0.44704),
+    (1.0, "mph", "km/h", 1.609344),
+    (98.6, "celsius", "fahrenheit", 209.48),
+    (-40.0, "celsius", "fahrenheit", -40.0),
+    (300.0, "celsius", "fahrenheit", 572.0),
+    (98.6, "celsius", "kelvin", 371.75),
+    (-40.0, "celsius", "kelvin", 233.14999999999998),
+    (300.0, "celsius", "kelvin", 573.15),
+    (98.6, "fahrenheit", "celsius", 37.0),
+    (-40.0, "fahrenheit", "celsius", -40.0),
+    (300.0, "fahrenheit", "celsius", 148.88888888888889),
+    (98.6, "fahrenheit", "kelvin", 310.15),
+    (-40.0, "fahrenheit", "kelvin", 233.14999999999998),
+    (300.0, "fahrenheit", "kelvin", 422.0388888888889),
+    (98.6, "kelvin", "celsius", -174.54999999999998),
+    (300.0, "kelvin", "celsius", 26.850000000000023),
+    (98.6, "kelvin", "fahrenheit", -282.18999999999994),
+    (300.0, "kelvin", "fahrenheit", 80.33000000000004),
+    (1.0, "byte", "kb", 0.0009765625),
+    (1.0, "byte", "mb", 9.5367431640625e-07),
+    (1.0, "byte", "gb", 9.313225746154785e-10),
+    (1.0, "kb", "byte", 1024.0),
+    (1.0, "kb", "mb", 0.0009765625),
+    (1.0, "kb", "gb", 9.5367431640625e-07),
+    (1.0, "mb", "byte", 1048576.0),
+    (1.0, "mb", "kb", 1024.0),
+    (1.0, "mb", "gb", 0.0009765625),
+    (1.0, "gb", "byte", 1073741824.0),
+    (1.0, "gb", "kb", 1048576.0),
+    (1.0, "gb", "mb", 1024.0),
+];
+
+#[test]
+fn registry_json_mengulang_hasil_awal() {
+    for &(nilai, dari, ke, lama) in HASIL_AWAL {
+        let hasil = konversi(nilai, dari, ke);
+        // Urutan operasi berbeda, jadi selisih beberapa ulp masih wajar
+        assert!(((hasil - lama) / lama).abs() < 1e-15, "{} {} -> {}: {} != {}", nilai, dari, ke, hasil, lama);
+    }
+}
+
+// Hasil yang tercetak di contoh dan dokumentasi lama harus tetap sama persis
+#[test]
+fn hasil_awal_tercetak_sama() {
+    assert_eq!(konversi(1.0, "m/s", "km/h").to_string(), "3.6");
+    assert_eq!(konversi(36.0, "kmh", "m/s").to_string(), "10");
+    assert_eq!(konversi(98.6, "fahrenheit", "celsius").to_string(), "37");
+    assert_eq!(konversi(0.0, "celsius", "fahrenheit").to_string(), "32");
+    assert_eq!(konversi(1.0, "inch", "cm").to_string(), "2.54");
+    assert_eq!(konversi(1.0, "miles", "km").to_string(), "1.609344");
+    assert_eq!(konversi(1.0, "lbs", "gram").to_string(), "453.59237");
+    assert_eq!(konversi(1.0, "gallon", "liter").to_string(), "3.785411784");
+    assert_eq!(konversi(2.0, "jam", "menit").to_string(), "120");
+    assert_eq!(konversi(90.0, "detik", "min").to_string(), "1.5");
+    assert_eq!(konversi(1.0, "mph", "m/s").to_string(), "0.44704");
+}
+
+// Setiap ejaan yang diterima sejak awal tetap bermakna sama: (ejaan, acuan, 1 ejaan dalam acuan)
+const EJAAN_AWAL: &[(&str, &str, f64)] = &[
+    ("celsius", "kelvin", 274.15),
+    ("fahrenheit", "kelvin", 255.92777777777775),
+    ("kelvin", "celsius", -272.15),
+    ("cm", "km", 1e-05),
+    ("inch", "cm", 2.54),
+    ("km", "cm", 100000.0),
+    ("miles", "km", 1.609344),
+    ("kg", "gram", 1000.0),
+    ("gram", "kg", 0.001),
+    ("lbs", "kg", 0.45359237),
+    ("liter", "ml", 1000.0),
+    ("l", "ml", 1000.0),
+    ("gallon", "liter", 3.785411784),
+    ("gal", "liter", 3.785411784),
+    ("ml", "liter", 0.001),
+    ("detik", "min", 0.016666666666666666),
+    ("second", "min", 0.016666666666666666),
+    ("sec", "min", 0.016666666666666666),
+    ("s", "min", 0.016666666666666666),
+    ("menit", "s", 60.0),
+    ("minute", "s", 60.0),
+    ("min", "s", 60.0),
+    ("jam", "s", 3600.0),
+    ("hour", "s", 3600.0),
+    ("h", "s", 3600.0),
+    ("km/h", "m/s", 0.2777777777777778),
+    ("kmh", "m/s", 0.2777777777777778),
+    ("mph", "m/s", 0.44704),
+    ("m/s", "km/h", 3.6),
+    ("byte", "kb", 0.0009765625),
+    ("b", "kb", 0.0009765625),
+    ("kb", "byte", 1024.0),
+    ("mb", "byte", 1048576.0),
+    ("gb", "byte", 1073741824.0),
+];
+
+#[test]
+fn ejaan_awal_bermakna_sama() {
+    for &(ejaan, acuan, lama) in EJAAN_AWAL {
+        // Pencocokan awal tidak peka huruf besar; "ML" kini simbol SI megaliter (lihat di bawah)
+        let besar = Some(ejaan.to_uppercase()).filter(|besar| besar != "ML");
+        for ejaan in std::iter::once(ejaan.to_string()).chain(besar) {
+            let hasil = konversi(1.0, &ejaan, acuan);
+            assert!(((hasil - lama) / lama).abs() < 1e-15, "1 {} -> {}: {} != {}", ejaan, acuan, hasil, lama);
+        }
+    }
+}
+
+// Ejaan awal yang maknanya sengaja diganti oleh permintaan berikutnya
+#[test]
+fn makna_awal_yang_berubah() {
+    // user-025: ounce memakai definisi persis 28.349523125 g, bukan 28.3495 g
+    assert_eq!(konversi(1.0, "ounce", "kg"), 0.028349523125);
+    // user-003: "m" menjadi meter agar awalan SI (km, mm, ...) punya satuan dasar; menit tetap "min".
+    // Simbol berawalan peka huruf besar, jadi "ML" kini megaliter dan bukan lagi mililiter
+    assert_eq!(konversi(1.0, "m", "cm"), 100.0);
+    assert_eq!(konversi(1.0, "M", "cm"), 100.0);
+    assert_eq!(konversi(1.0, "ML", "L"), 1e6);
+    // user-024: "ms" menjadi milidetik; m/s tetap "m/s" atau "mps"
+    assert_eq!(konversi(1.0, "ms", "s"), 0.001);
+    assert!(UnitRegistry::bawaan().urai("MS").is_err());
+    assert_eq!(konversi(1.0, "mps", "km/h"), 3.6);
+}
+
+// Hasil kali faktor dengan nilai besar tidak boleh dilaporkan sebagai overflow bila hasilnya muat
+#[test]
+fn nilai_besar_tanpa_overflow_palsu() {
+    let hasil = konversi(1e301, "gal_us", "L");
+    assert!(((hasil - 3.785411784e301) / 3.785411784e301).abs() < 1e-15, "{}", hasil);
+    assert_eq!(konversi(1e308, "km", "km"), 1e308);
+
+    let registry = UnitRegistry::bawaan();
+    let km = registry.urai("km").unwrap();
+    let cm = registry.urai("cm").unwrap();
+    assert!(matches!(converter::konversi(1e305, &km, &cm), Err(KonversiError::Overflow { .. })));
+}
@@ -0,0 +1,38 @@
+use minirustcli::konversi_csv::{self, OpsiCsv, SumberSatuan};
+use minirustcli::pembulatan::FormatAngka;
+use minirustcli::pesan;
+use std::path::PathBuf;
+
+fn opsi(input: PathBuf, keluaran: PathBuf, kolom: &str) -> OpsiCsv {
+    OpsiCsv {
+        input: Some(input),
+        keluaran: Some(keluaran),
+        kolom: kolom.to_string(),
+        sumber: SumberSatuan::Tetap("km".to_string()),
+        tujuan: "m".to_string(),
+        kolom_baru: Some(String::new()),
+        tanpa_header: false,
+        pemisah: b',',
+        angka: FormatAngka::default(),
+    }
+}
+
+// Indeks kolom di luar lebar header ditolak sebelum header keluaran ditulis, bukan panic
+#[test]
+fn indeks_kolom_diperiksa_terhadap_header() {
+    let direktori = std::env::temp_dir().join(format!("unitconv-csv-{}", std::process::id()));
+    std::fs::create_dir_all(&direktori).unwrap();
+    let input = direktori.join("jarak.csv");
+    let keluaran = direktori.join("hasil.csv");
+    std::fs::write(&input, "nama,jarak\nA,1.5\n").unwrap();
+
+    let galat = konversi_csv::jalankan(&opsi(input.clone(), keluaran.clone(), "5")).err();
+    assert_eq!(galat, Some(pesan::format("csv.kolom_tidak_ditemukan", &[&"5", &"nama, jarak"])));
+    assert!(konversi_csv::jalankan(&opsi(input.clone(), keluaran.clone(), "3")).is_err());
+
+    let ringkasan = konversi_csv::jalankan(&opsi(input, keluaran.clone(), "2")).unwrap();
+    assert_eq!(ringkasan.berhasil, 1);
+    assert_eq!(std::fs::read_to_string(&keluaran).unwrap(), "nama,jarak,jarak_m\nA,1.5,1500\n");
+
+    std::fs::remove_dir_all(&direktori).unwrap();
+}
@@ -0,0 +1,89 @@
+use minirustcli::converter::KonversiError;
+use minirustcli::models::Kuantitas;
+use std::cmp::Ordering;
+
+fn kuantitas(teks: &str) -> Kuantitas {
+    teks.parse().unwrap_or_else(|e| panic!("'{}' gagal diurai: {}", teks, e))
+}
+
+fn hampir(hasil: f64, acuan: f64) {
+    assert!((hasil - acuan).abs() <= acuan.abs() * 1e-12, "{} != {}", hasil, acuan);
+}
+
+#[test]
+fn urai_dari_teks() {
+    let berat = kuantitas("12.5 kg");
+    assert_eq!((berat.nilai, berat.satuan.simbol()), (12.5, "kg"));
+    let suhu = kuantitas("-40°F");
+    assert_eq!((suhu.nilai, suhu.satuan.simbol()), (-40.0, "°F"));
+    let laju = kuantitas("3 km/h");
+    assert_eq!((laju.nilai, laju.satuan.simbol()), (3.0, "km/h"));
+
+    assert!(matches!("kg".parse::<Kuantitas>(), Err(KonversiError::NilaiTidakValid(_))));
+    assert!(matches!("12.5".parse::<Kuantitas>(), Err(KonversiError::NilaiTidakValid(_))));
+    assert!(matches!("1e400 m".parse::<Kuantitas>(), Err(KonversiError::NilaiTidakHingga)));
+    assert!(matches!("3 xyzzy".parse::<Kuantitas>(), Err(KonversiError::SatuanTidakDikenali { .. })));
+}
+
+#[test]
+fn serde_bolak_balik() {
+    let asal = kuantitas("12.5 kg");
+    let json = serde_json::to_string(&asal).unwrap();
+    assert_eq!(json, "\"12.5 kg\"");
+    let kembali: Kuantitas = serde_json::from_str(&json).unwrap();
+    assert_eq!((kembali.nilai, kembali.satuan.simbol()), (12.5, "kg"));
+
+    let daftar: Vec<Kuantitas> = serde_json::from_str(r#"["1 km", "-40 °C"]"#).unwrap();
+    assert_eq!(serde_json::to_string(&daftar).unwrap(), r#"["1 km","-40 °C"]"#);
+    assert!(serde_json::from_str::<Kuantitas>("\"3 xyzzy\"").is_err());
+    assert!(serde_json::from_str::<Kuantitas>("3").is_err());
+}
+
+#[test]
+fn jumlah_dan_kurang_dalam_satuan_kiri() {
+    let hasil = (kuantitas("1 km") + kuantitas("500 m")).unwrap();
+    assert_eq!((hasil.nilai, hasil.satuan.simbol()), (1.5, "km"));
+    let hasil = (kuantitas("1 h") - kuantitas("30 min")).unwrap();
+    assert_eq!((hasil.nilai, hasil.satuan.simbol()), (0.5, "h"));
+
+    assert!(matches!(kuantitas("1 km") + kuantitas("1 kg"), Err(KonversiError::DimensiBerbeda { .. })));
+    assert!(matches!(kuantitas("1 km") - kuantitas("1 s"), Err(KonversiError::DimensiBerbeda { .. })));
+}
+
+#[test]
+fn kali_dan_bagi_menggabungkan_satuan() {
+    let luas = (kuantitas("2 km") * kuantitas("3 m")).unwrap();
+    assert_eq!((luas.nilai, luas.satuan.simbol()), (6.0, "km·m"));
+    hampir(luas.to(&kuantitas("1 m²").satuan).unwrap().nilai, 6000.0);
+
+    let laju = (kuantitas("120 km") / kuantitas("1.5 h")).unwrap();
+    assert_eq!((laju.nilai, laju.satuan.simbol()), (80.0, "km/h"));
+    hampir(laju.to(&kuantitas("1 m/s").satuan).unwrap().nilai, 80.0 / 3.6);
+    // Satuan gabungan dibungkus kurung agar tidak terbaca lain
+    let percepatan = (laju / kuantitas("2 s")).unwrap();
+    assert_eq!(percepatan.satuan.simbol(), "(km/h)/s");
+
+    let skalar = 2.0 * kuantitas("3 m") / 4.0;
+    assert_eq!((skalar.nilai, skalar.satuan.simbol()), (1.5, "m"));
+
+    // Pangkat dimensi hasil di luar rentang i8
+    let mut besar = kuantitas("1 m");
+    for _ in 0..6 {
+        besar = (besar.clone() * besar).unwrap();
+    }
+    assert!(matches!(besar.clone() * besar, Err(KonversiError::SatuanTidakValid { .. })));
+}
+
+#[test]
+fn bandingkan_lintas_satuan() {
+    assert!(kuantitas("1 km") > kuantitas("500 m"));
+    assert!(kuantitas("1 lbs") < kuantitas("1 kg"));
+    assert_eq!(kuantitas("1 km"), kuantitas("1000 m"));
+    assert_eq!(kuantitas("0 °C").partial_cmp(&kuantitas("273.15 K")), Some(Ordering::Equal));
+    assert!(kuantitas("32 °F") < kuantitas("1 °C"));
+
+    // Beda dimensi tidak dapat dibandingkan dan tidak pernah sama
+    assert_eq!(kuantitas("1 km").partial_cmp(&kuantitas("1 kg")), None);
+    assert_ne!(kuantitas("1 km"), kuantitas("1 kg"));
+    assert!(!kuantitas("1 km").ge(&kuantitas("1 kg")) && !kuantitas("1 km").lt(&kuantitas("1 kg")));
+}
@@ -0,0 +1,90 @@
+use minirustcli::converter::KonversiError;
+use minirustcli::eksak::OpsiEksak;
+use minirustcli::keluaran;
+use minirustcli::lokal::Lokal;
+use minirustcli::models::UnitRegistry;
+
+#[test]
+fn kode_lokal_dari_teks() {
+    assert_eq!("id-ID".parse::<Lokal>(), Ok(Lokal::ID_ID));
+    assert_eq!("id_ID.UTF-8".parse::<Lokal>(), Ok(Lokal::ID_ID));
+    assert_eq!("de".parse::<Lokal>(), Ok(Lokal::DE_DE));
+    assert_eq!("en_AU".parse::<Lokal>(), Ok(Lokal::EN_US));
+    assert_eq!("POSIX".parse::<Lokal>(), Ok(Lokal::BAWAAN));
+    assert!("xx-YY".parse::<Lokal>().is_err());
+}
+
+#[test]
+fn normalkan_ke_bentuk_kanonik() {
+    let normal = |lokal: Lokal, teks| lokal.normalkan(teks);
+    assert_eq!(normal(Lokal::ID_ID, "1.234,5").as_deref(), Some("1234.5"));
+    assert_eq!(normal(Lokal::ID_ID, "1234,5").as_deref(), Some("1234.5"));
+    assert_eq!(normal(Lokal::ID_ID, "−1.234.567").as_deref(), Some("-1234567"));
+    assert_eq!(normal(Lokal::EN_US, " 1,234.5 ").as_deref(), Some("1234.5"));
+    // Spasi biasa diterima sebagai pemisah ribuan lokal berspasi
+    assert_eq!(normal(Lokal::FR_FR, "1 234,5").as_deref(), Some("1234.5"));
+    assert_eq!(normal(Lokal::SV_SE, "−2,5").as_deref(), Some("-2.5"));
+    // Lokal bawaan tidak mengenal pemisah ribuan
+    assert_eq!(normal(Lokal::BAWAAN, "−1.5").as_deref(), Some("-1.5"));
+
+    // Pemisah salah tempat ditolak, bukan dibaca sebagai angka lain
+    assert_eq!(normal(Lokal::ID_ID, "1,234.5"), None);
+    assert_eq!(normal(Lokal::ID_ID, "12.34"), None);
+    assert_eq!(normal(Lokal::ID_ID, "1,2,3"), None);
+    assert_eq!(normal(Lokal::EN_US, "1,23"), None);
+    assert_eq!(normal(Lokal::EN_US, "1234,567.5"), None);
+}
+
+#[test]
+fn urai_nilai_lokal() {
+    assert_eq!(Lokal::ID_ID.urai_nilai("1.234,5").unwrap(), 1234.5);
+    assert_eq!(Lokal::DE_DE.urai_nilai("-0,25").unwrap(), -0.25);
+    assert_eq!(Lokal::EN_US.urai_nilai("1.5e3").unwrap(), 1500.0);
+    assert_eq!(Lokal::BAWAAN.urai_nilai("2.5").unwrap(), 2.5);
+
+    assert!(matches!(Lokal::ID_ID.urai_nilai("1,234.5"), Err(KonversiError::NilaiTidakValid(t)) if t == "1,234.5"));
+    assert!(matches!(Lokal::BAWAAN.urai_nilai("1,5"), Err(KonversiError::NilaiTidakValid(_))));
+    assert!(matches!(Lokal::ID_ID.urai_nilai("1e400"), Err(KonversiError::NilaiTidakHingga)));
+}
+
+#[test]
+fn terapkan_pemisah_lokal() {
+    assert_eq!(Lokal::ID_ID.terapkan("-1234567.25"), "-1.234.567,25");
+    assert_eq!(Lokal::ID_ID.terapkan("123"), "123");
+    assert_eq!(Lokal::EN_US.terapkan("1234.5"), "1,234.5");
+    assert_eq!(Lokal::FR_FR.terapkan("1234.5"), "1\u{202f}234,5");
+    assert_eq!(Lokal::SV_SE.terapkan("-1234.5"), "−1\u{a0}234,5");
+    assert_eq!(Lokal::BAWAAN.terapkan("-1234567.25"), "-1234567.25");
+}
+
+#[test]
+fn notasi_ilmiah_di_luar_ambang() {
+    assert_eq!(Lokal::ID_ID.format(999999999999999.0), "999.999.999.999.999");
+    assert_eq!(Lokal::ID_ID.format(1e15), "1e15");
+    assert_eq!(Lokal::ID_ID.format(-1.5e20), "-1,5e20");
+    assert_eq!(Lokal::ID_ID.format(0.000001), "0,000001");
+    assert_eq!(Lokal::ID_ID.format(2.5e-7), "2,5e-7");
+    assert_eq!(Lokal::SV_SE.format(-2.5e-7), "−2,5e−7");
+    assert_eq!(Lokal::ID_ID.format(0.0), "0");
+    assert_eq!(Lokal::ID_ID.format(f64::INFINITY), "inf");
+    // Lokal bawaan mempertahankan keluaran lama
+    assert_eq!(Lokal::BAWAAN.format(1e20), "100000000000000000000");
+    assert_eq!(Lokal::BAWAAN.format(2.5e-7), "0.00000025");
+}
+
+#[test]
+fn konversi_eksak_mengikuti_lokal() {
+    let registry = UnitRegistry::bawaan();
+    let kg = registry.urai("kg").unwrap();
+    let lbs = registry.urai("lbs").unwrap();
+
+    let teks = keluaran::format_konversi_eksak(1234.5, &kg, &lbs, OpsiEksak::default(), Lokal::ID_ID).unwrap();
+    assert!(teks.starts_with("1.234,5 kg = 123.450.000.000/45.359.237 lbs (1.234.500 g, "), "{}", teks);
+    assert!(teks.contains(", 1,2345 t,"), "{}", teks);
+
+    let teks = keluaran::format_konversi_eksak(1234.5, &kg, &lbs, OpsiEksak { digit: Some(2) }, Lokal::ID_ID).unwrap();
+    assert!(teks.starts_with("1.234,5 kg = 2.721,61 lbs (1.234.500,00 g, "), "{}", teks);
+
+    let teks = keluaran::format_konversi_eksak(1234.5, &kg, &lbs, OpsiEksak::default(), Lokal::BAWAAN).unwrap();
+    assert!(teks.starts_with("1234.5 kg = 123450000000/45359237 lbs (1234500 g, "), "{}", teks);
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use common::{hampir, konversi};
+
+// NIST SP 811 lampiran B.8, faktor ke meter persegi (kaki internasional)
+#[test]
+fn faktor_imperial_ke_meter_persegi() {
+    hampir(konversi(1.0, "ft²", "m²"), 9.290304e-2);
+    hampir(konversi(1.0, "in²", "m²"), 6.4516e-4);
+    hampir(konversi(1.0, "mi²", "m²"), 2.589988e6);
+    // Acre internasional = 43 560 ft²; NIST masih mencantumkan acre survei AS 4.046 873 E+03
+    hampir(konversi(1.0, "acre", "m²"), 4.046856e3);
+    hampir(konversi(1.0, "acre", "ft²"), 43560.0);
+    hampir(konversi(640.0, "acre", "mi²"), 1.0);
+}
+
+// Brosur SI edisi 9, tabel 8 (hektare) dan NIST SP 811 lampiran B.8 (are)
+#[test]
+fn hektare_dan_are() {
+    hampir(konversi(1.0, "ha", "m²"), 1.0e4);
+    hampir(konversi(1.0, "are", "m²"), 1.0e2);
+    hampir(konversi(1.0, "km²", "ha"), 100.0);
+}
+
+// Ukuran tanah tradisional: bau (bouw) Hindia Belanda = 500 roede persegi Rijnland
+// (roede 3,76739 m), lazim dibulatkan 7096,5 m²; tumbak Jawa Barat lazim dihitung 14 m²
+#[test]
+fn satuan_tanah_tradisional() {
+    hampir(konversi(1.0, "tumbak", "m²"), 14.0);
+    hampir(konversi(1.0, "bau", "m²"), 7096.5);
+    let roede: f64 = 3.76739;
+    assert!((konversi(1.0, "bau", "m²") / (500.0 * roede * roede) - 1.0).abs() < 1e-4);
+    hampir(konversi(1.0, "bouw", "ha"), 0.70965);
+}
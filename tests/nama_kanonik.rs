@@ -0,0 +1,20 @@
+use minirustcli::models::UnitRegistry;
+use minirustcli::pesan::{self, Bahasa};
+
+// Bahasa antarmuka dipasang sekali per proses; satu-satunya tes di berkas ini
+#[test]
+fn nama_kanonik_tidak_ikut_bahasa() {
+    pesan::pasang_bahasa(Bahasa::dari_kode("en").unwrap()).unwrap();
+    let registry = UnitRegistry::bawaan();
+
+    let pon = registry.urai("lbs").unwrap();
+    assert_eq!(pon.nama_kanonik(), "lbs");
+    assert_eq!(pon.nama(), "pound");
+    // Nama semua bahasa tetap menjadi alias, apa pun bahasa aktifnya
+    for ejaan in ["pound", "pon"] {
+        assert!(pon.alias().iter().any(|a| a == ejaan), "alias '{}' hilang", ejaan);
+    }
+
+    let kaki = registry.urai("kaki").unwrap();
+    assert_eq!((kaki.nama_kanonik(), kaki.nama()), ("foot", "foot"));
+}
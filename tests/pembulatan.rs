@@ -0,0 +1,75 @@
+use minirustcli::lokal::Lokal;
+use minirustcli::pembulatan::{hitung_angka_penting, FormatAngka, ModePembulatan, Presisi};
+
+fn format(presisi: Presisi, mode: ModePembulatan, nilai: f64) -> String {
+    FormatAngka { presisi, mode, lokal: Lokal::BAWAAN }.format(nilai)
+}
+
+#[test]
+fn jumlah_desimal_tetap() {
+    let desimal = |n, nilai| format(Presisi::Desimal(n), ModePembulatan::HalfEven, nilai);
+    assert_eq!(desimal(2, 1.0), "1.00");
+    assert_eq!(desimal(3, 2.20462262), "2.205");
+    assert_eq!(desimal(0, 2.5), "2");
+    assert_eq!(desimal(2, 9.999), "10.00");
+    assert_eq!(desimal(2, 0.001), "0.00");
+    // Nol hasil pembulatan tidak bertanda
+    assert_eq!(desimal(2, -0.001), "0.00");
+    assert_eq!(desimal(1, -12.34), "-12.3");
+    assert_eq!(format(Presisi::Penuh, ModePembulatan::HalfEven, 2.20462262), "2.20462262");
+}
+
+#[test]
+fn mode_pembulatan_di_titik_tengah() {
+    let desimal = |mode, nilai| format(Presisi::Desimal(2), mode, nilai);
+    // Pembulatan bekerja pada digit desimal, bukan pada biner f64 (2.675 = 2.67499999...)
+    assert_eq!(desimal(ModePembulatan::HalfEven, 2.675), "2.68");
+    assert_eq!(desimal(ModePembulatan::HalfEven, 2.665), "2.66");
+    assert_eq!(desimal(ModePembulatan::HalfEven, 2.6651), "2.67");
+    assert_eq!(desimal(ModePembulatan::HalfUp, 2.665), "2.67");
+    assert_eq!(desimal(ModePembulatan::HalfUp, -2.665), "-2.67");
+    assert_eq!(desimal(ModePembulatan::HalfUp, 9.995), "10.00");
+    assert_eq!(desimal(ModePembulatan::Truncate, 2.679), "2.67");
+    assert_eq!(desimal(ModePembulatan::Truncate, -2.679), "-2.67");
+}
+
+#[test]
+fn angka_penting_tetap() {
+    let penting = |n, nilai| format(Presisi::AngkaPenting(n), ModePembulatan::HalfEven, nilai);
+    assert_eq!(penting(3, 0.00123456), "0.00123");
+    assert_eq!(penting(3, 123456.0), "123000");
+    assert_eq!(penting(3, 2.0), "2.00");
+    assert_eq!(penting(2, 99.7), "100");
+    assert_eq!(penting(1, -0.25), "-0.2");
+    assert_eq!(format(Presisi::AngkaPenting(1), ModePembulatan::HalfUp, -0.25), "-0.3");
+}
+
+#[test]
+fn angka_penting_dihitung_dari_teks_masukan() {
+    assert_eq!(hitung_angka_penting("10"), 2);
+    assert_eq!(hitung_angka_penting("10.0"), 3);
+    assert_eq!(hitung_angka_penting("98.6"), 3);
+    assert_eq!(hitung_angka_penting("0.0045"), 2);
+    assert_eq!(hitung_angka_penting("-0.500"), 3);
+    assert_eq!(hitung_angka_penting("1.50e3"), 3);
+    assert_eq!(hitung_angka_penting("1.234,50"), 6);
+    assert_eq!(hitung_angka_penting("0"), 1);
+
+    let otomatis = FormatAngka { presisi: Presisi::Otomatis, ..FormatAngka::default() };
+    // 10.0 kg -> lbs: nol di belakang ikut menentukan presisi
+    assert_eq!(otomatis.untuk_teks("10.0").format(22.046226218), "22.0");
+    assert_eq!(otomatis.untuk_teks("10").format(22.046226218), "22");
+    // Teks f64 kehilangan nol di belakang; untuk_input hanya untuk nilai tanpa teks asli
+    assert_eq!(otomatis.untuk_input(10.0).format(22.046226218), "22");
+    // Presisi lain tidak terpengaruh teks masukan
+    let tetap = FormatAngka { presisi: Presisi::Desimal(1), ..FormatAngka::default() };
+    assert_eq!(tetap.untuk_teks("10.000"), tetap);
+}
+
+#[test]
+fn pembulatan_mengikuti_lokal() {
+    let angka = FormatAngka { presisi: Presisi::Desimal(2), mode: ModePembulatan::HalfUp, lokal: Lokal::ID_ID };
+    assert_eq!(angka.format(-1234567.891), "-1.234.567,89");
+    let angka = FormatAngka { presisi: Presisi::Otomatis, lokal: Lokal::ID_ID, ..FormatAngka::default() };
+    assert_eq!(angka.untuk_teks("1.000,0").format(2204.62262), "2.204,6");
+}
@@ -0,0 +1,59 @@
+use clap::CommandFactory;
+use minirustcli::cli::Cli;
+use minirustcli::pesan::{self, Bahasa};
+
+#[test]
+fn setiap_kunci_ada_di_semua_katalog() {
+    for &bahasa in Bahasa::semua() {
+        for &lain in Bahasa::semua() {
+            let hilang: Vec<&String> = pesan::katalog(bahasa)
+                .keys()
+                .filter(|kunci| !pesan::katalog(lain).contains_key(*kunci))
+                .collect();
+            assert!(hilang.is_empty(), "kunci di katalog '{}' tidak ada di '{}': {:?}", bahasa.kode(), lain.kode(), hilang);
+        }
+    }
+}
+
+#[test]
+fn terjemahan_memakai_argumen_yang_sama() {
+    let acuan = pesan::katalog(Bahasa::Id);
+    for &bahasa in Bahasa::semua() {
+        for (kunci, teks) in pesan::katalog(bahasa) {
+            let Some(teks_acuan) = acuan.get(kunci) else { continue };
+            assert_eq!(
+                pesan::argumen_templat(teks),
+                pesan::argumen_templat(teks_acuan),
+                "argumen '{}' di katalog '{}' berbeda",
+                kunci,
+                bahasa.kode()
+            );
+        }
+    }
+}
+
+#[test]
+fn setiap_argumen_cli_punya_teks_bantuan() {
+    fn periksa(perintah: &clap::Command, jalur: &str, hilang: &mut Vec<String>) {
+        let mut kunci: Vec<String> = perintah.get_arguments().map(|a| format!("{}.{}", jalur, a.get_id())).collect();
+        if jalur != "bantuan" {
+            kunci.push(format!("{}.tentang", jalur));
+        }
+        hilang.extend(kunci.into_iter().filter(|k| !pesan::katalog(Bahasa::Id).contains_key(k)));
+        for sub in perintah.get_subcommands() {
+            periksa(sub, &format!("{}.{}", jalur, sub.get_name()), hilang);
+        }
+    }
+
+    let mut hilang = Vec::new();
+    periksa(&Cli::command(), "bantuan", &mut hilang);
+    assert!(hilang.is_empty(), "teks bantuan belum ada di katalog: {:?}", hilang);
+}
+
+#[test]
+fn bahasa_dari_kode_lokal() {
+    assert_eq!(Bahasa::dari_kode("en_US.UTF-8"), Some(Bahasa::En));
+    assert_eq!(Bahasa::dari_kode("id_ID"), Some(Bahasa::Id));
+    assert_eq!(Bahasa::dari_kode("en"), Some(Bahasa::En));
+    assert_eq!(Bahasa::dari_kode("C"), None);
+}
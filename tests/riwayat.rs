@@ -0,0 +1,21 @@
+use minirustcli::history;
+
+// Riwayat ditulis di direktori kerja; satu-satunya tes di berkas ini sehingga aman berpindah direktori
+#[test]
+fn nilai_tidak_hingga_tidak_merusak_riwayat() {
+    let direktori = std::env::temp_dir().join(format!("unitconv-riwayat-{}", std::process::id()));
+    std::fs::create_dir_all(&direktori).unwrap();
+    std::env::set_current_dir(&direktori).unwrap();
+
+    history::simpan_riwayat("m", "km", 1.0, Some(0.001), None);
+    history::simpan_riwayat("m", "km", f64::INFINITY, Some(f64::INFINITY), None);
+    history::simpan_riwayat("m", "km", 1e300, Some(f64::NAN), None);
+    history::simpan_riwayat("m", "km", 2.0, Some(0.002), None);
+
+    let riwayat = history::baca_riwayat().unwrap();
+    let input: Vec<f64> = riwayat.iter().map(|c| c.nilai_input).collect();
+    assert_eq!(input, [1.0, 2.0]);
+    assert!(!direktori.join("conversion.json.bak").exists());
+
+    std::fs::remove_dir_all(&direktori).unwrap();
+}
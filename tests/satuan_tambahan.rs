@@ -0,0 +1,50 @@
+mod common;
+
+use common::{hampir, konversi};
+use minirustcli::dimensi::Dimensi;
+use minirustcli::models::UnitRegistry;
+
+// NIST SP 811 lampiran B.8, faktor ke kilogram; stone dari UK Weights and Measures Act 1985
+#[test]
+fn faktor_massa() {
+    hampir(konversi(1.0, "tn", "kg"), 9.071847e2);
+    hampir(konversi(1.0, "long tn", "kg"), 1.016047e3);
+    hampir(konversi(1.0, "st", "kg"), 6.350293);
+    hampir(konversi(1.0, "st", "lbs"), 14.0);
+    hampir(konversi(1.0, "ton_us", "lbs"), 2000.0);
+    hampir(konversi(1.0, "ton_uk", "lbs"), 2240.0);
+}
+
+// NIST SP 811 lampiran B.8: barrel minyak = 42 galon AS
+#[test]
+fn faktor_barrel() {
+    hampir(konversi(1.0, "bbl", "m³"), 1.589873e-1);
+    hampir(konversi(1.0, "bbl", "gal"), 42.0);
+}
+
+// Minggu dan fortnight dari hari 86 400 s; tahun Julian 365,25 hari (IAU)
+#[test]
+fn faktor_waktu() {
+    hampir(konversi(1.0, "wk", "s"), 6.048e5);
+    hampir(konversi(1.0, "fortnight", "s"), 1.2096e6);
+    hampir(konversi(1.0, "fortnight", "wk"), 2.0);
+    hampir(konversi(1.0, "yr_j", "s"), 3.15576e7);
+    hampir(konversi(1.0, "yr_j", "d"), 365.25);
+}
+
+// "ms" adalah milidetik, bukan meter·detik
+#[test]
+fn ms_adalah_milidetik() {
+    let ms = UnitRegistry::bawaan().urai("ms").unwrap();
+    assert_eq!(ms.dimensi(), Dimensi::WAKTU);
+    assert_eq!(ms.ke_dasar(1.0), 0.001);
+    hampir(konversi(1500.0, "ms", "s"), 1.5);
+}
+
+#[test]
+fn alias_mps() {
+    let registry = UnitRegistry::bawaan();
+    assert_eq!(registry.urai("mps").unwrap().simbol(), "m/s");
+    hampir(konversi(10.0, "mps", "km/h"), 36.0);
+    hampir(konversi(1.0, "mps", "m/s"), 1.0);
+}
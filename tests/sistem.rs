@@ -0,0 +1,67 @@
+use minirustcli::converter;
+use minirustcli::kalkulator;
+use minirustcli::models::{Sistem, UnitRegistry};
+use minirustcli::saran;
+
+fn konversi_di(registry: &UnitRegistry, nilai: f64, dari: &str, ke: &str) -> f64 {
+    let dari = registry.urai(dari).unwrap();
+    let ke = registry.urai(ke).unwrap();
+    converter::konversi(nilai, &dari, &ke).unwrap()
+}
+
+fn hampir(hasil: f64, acuan: f64) {
+    assert!(((hasil - acuan) / acuan).abs() < 1e-12, "{} berbeda dari acuan {}", hasil, acuan);
+}
+
+#[test]
+fn ejaan_tegas_tidak_bergantung_sistem() {
+    for sistem in [Sistem::Us, Sistem::Imperial] {
+        let mut registry = UnitRegistry::bawaan();
+        registry.atur_sistem(sistem);
+        hampir(konversi_di(&registry, 1.0, "gal_us", "L"), 3.785411784);
+        hampir(konversi_di(&registry, 1.0, "gal_imp", "L"), 4.54609);
+        hampir(konversi_di(&registry, 1.0, "floz_us", "ml"), 29.5735295625);
+        hampir(konversi_di(&registry, 1.0, "floz_imp", "ml"), 28.4130625);
+        hampir(konversi_di(&registry, 1.0, "oz_t", "g"), 31.1034768);
+        hampir(konversi_di(&registry, 1.0, "oz", "g"), 28.349523125);
+    }
+}
+
+#[test]
+fn ejaan_ambigu_mengikuti_sistem_bawaan() {
+    let mut registry = UnitRegistry::bawaan();
+    hampir(konversi_di(&registry, 1.0, "gal", "L"), 3.785411784);
+    hampir(konversi_di(&registry, 2.0, "pints", "L"), 0.946352946);
+
+    registry.atur_sistem(Sistem::Imperial);
+    hampir(konversi_di(&registry, 1.0, "gal", "L"), 4.54609);
+    hampir(konversi_di(&registry, 1.0, "Gallon", "L"), 4.54609);
+    hampir(konversi_di(&registry, 1.0, "fl oz", "ml"), 28.4130625);
+    hampir(konversi_di(&registry, 1.0, "qt", "L"), 1.1365225);
+    hampir(konversi_di(&registry, 30.0, "mi/gal", "km/L"), 30.0 * 1.609344 / 4.54609);
+}
+
+#[test]
+fn tafsiran_ambigu_menyebut_varian_lain() {
+    let registry = UnitRegistry::bawaan();
+    let (pilihan, lain) = registry.tafsiran_ambigu("gallons").unwrap();
+    assert_eq!(pilihan.sistem(), Some(Sistem::Us));
+    assert_eq!(lain.sistem(), Some(Sistem::Imperial));
+    assert_eq!(registry.ejaan_tegas(lain), "gal_imp");
+
+    assert!(registry.tafsiran_ambigu("gal_us").is_none());
+    assert!(registry.tafsiran_ambigu("L").is_none());
+}
+
+// Catatan dikembalikan ke pemanggil, bukan dicetak oleh pustaka
+#[test]
+fn catatan_tafsiran_dikembalikan() {
+    let registry = UnitRegistry::bawaan();
+    assert_eq!(saran::catatan_tafsiran(&registry, "mi/gal").len(), 1);
+    assert_eq!(saran::catatan_konversi(&registry, "gal", "pint").len(), 2);
+    assert!(saran::catatan_konversi(&registry, "gal_us", "L").is_empty());
+
+    let hasil = kalkulator::evaluasi(&registry, "2 gal + 1 pint", Some("L")).unwrap();
+    assert_eq!(hasil.catatan.len(), 2);
+    assert!(kalkulator::evaluasi(&registry, "2 m + 1 cm", None).unwrap().catatan.is_empty());
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use common::{hampir, konversi, konversi_di};
+use minirustcli::definisi;
+use minirustcli::eksak;
+use minirustcli::models::UnitRegistry;
+
+fn konversi_eksak(registry: &UnitRegistry, nilai: &str, dari: &str, ke: &str) -> String {
+    let dari = registry.urai(dari).unwrap();
+    let ke = registry.urai(ke).unwrap();
+    let nilai = eksak::dari_teks(nilai).unwrap();
+    eksak::format(&eksak::konversi(&nilai, &dari, &ke).unwrap(), None)
+}
+
+// NIST SP 811 lampiran B.8, faktor ke pascal
+#[test]
+fn faktor_ke_pascal() {
+    hampir(konversi(1.0, "atm", "Pa"), 1.01325e5);
+    hampir(konversi(1.0, "bar", "Pa"), 1.0e5);
+    hampir(konversi(1.0, "mbar", "Pa"), 1.0e2);
+    hampir(konversi(1.0, "psi", "Pa"), 6.894757e3);
+    hampir(konversi(1.0, "mmHg", "Pa"), 1.333224e2);
+    hampir(konversi(1.0, "inHg", "Pa"), 3.386389e3);
+    hampir(konversi(1.0, "torr", "Pa"), 1.333224e2);
+    hampir(konversi(1.0, "kgf/cm²", "Pa"), 9.80665e4);
+    hampir(konversi(1.0, "kPa", "Pa"), 1.0e3);
+    hampir(konversi(1.0, "MPa", "Pa"), 1.0e6);
+}
+
+#[test]
+fn satu_atmosfer_standar() {
+    hampir(konversi(1.0, "atm", "kPa"), 101.325);
+    hampir(konversi(1.0, "atm", "bar"), 1.01325);
+    hampir(konversi(1.0, "atm", "psi"), 14.69595);
+    hampir(konversi(1.0, "atm", "torr"), 760.0);
+    hampir(konversi(1.0, "atm", "mmHg"), 759.9999);
+    hampir(konversi(1.0, "atm", "inHg"), 29.92126);
+    hampir(konversi(1.0, "atm", "kgf/cm2"), 1.033227);
+    hampir(konversi(1.0, "bar", "psi"), 14.50377);
+}
+
+#[test]
+fn tekanan_ukur_dengan_atmosfer_standar() {
+    hampir(konversi(0.0, "psig", "psia"), 14.69595);
+    hampir(konversi(32.0, "psig", "psia"), 46.69595);
+    hampir(konversi(2.2, "barg", "bara"), 3.21325);
+    assert!(konversi(101.325, "kPa", "kPag").abs() < 1e-9);
+    hampir(konversi(30.0, "psig", "barg"), 2.068427);
+}
+
+#[test]
+fn tekanan_ukur_dengan_atmosfer_acuan() {
+    let mut registry = UnitRegistry::bawaan();
+    definisi::atur_atmosfer(&mut registry, "100000").unwrap();
+    hampir(konversi_di(&registry, 0.0, "kPag", "kPa"), 100.0);
+    hampir(konversi_di(&registry, 1.0, "barg", "bar"), 2.0);
+
+    definisi::atur_atmosfer(&mut registry, "14.7 psi").unwrap();
+    hampir(konversi_di(&registry, 30.0, "psig", "psi"), 44.7);
+
+    assert!(definisi::atur_atmosfer(&mut registry, "1 kg").is_err());
+    assert!(definisi::atur_atmosfer(&mut registry, "-5").is_err());
+}
+
+// Offset ukur disimpan sebagai pecahan persis sehingga --exact tidak membawa sisa galat f64
+#[test]
+fn tekanan_ukur_persis() {
+    let mut registry = UnitRegistry::bawaan();
+    assert_eq!(konversi_eksak(&registry, "0", "psig", "Pa"), "101325");
+    assert_eq!(konversi_eksak(&registry, "101325", "Pa", "psig"), "0");
+    assert_eq!(konversi_eksak(&registry, "0", "barg", "atm"), "1");
+    assert_eq!(konversi_eksak(&registry, "1.5", "barg", "kPag"), "150");
+
+    definisi::atur_atmosfer(&mut registry, "14.7 psi").unwrap();
+    assert_eq!(konversi_eksak(&registry, "0", "psig", "psi"), "14.7");
+    assert_eq!(konversi_eksak(&registry, "30", "psig", "psi"), "44.7");
+    definisi::atur_atmosfer(&mut registry, "1013.25 hPa").unwrap();
+    assert_eq!(konversi_eksak(&registry, "0", "kPag", "Pa"), "101325");
+
+    assert!(definisi::atur_atmosfer(&mut registry, "1e400").is_err());
+}